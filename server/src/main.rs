@@ -30,8 +30,9 @@ cfg_if! {
 mod routes;
 mod utils;
 
-use routes::{health, history, simulation};
+use routes::{calibration, health, history, metrics, rating, simulation};
 use utils::http::timer_middleware;
+use utils::state::AppState;
 
 const ALLOWED_ORIGINS: &[&str] = &["http://localhost:5173", "https://odds.nmckee.org"];
 
@@ -54,6 +55,8 @@ async fn main() {
         .await
         .expect("Failed to connect to Postgres");
 
+    let state = AppState::new(pool);
+
     let cors = CorsLayer::new()
         .allow_origin(
             ALLOWED_ORIGINS
@@ -67,9 +70,17 @@ async fn main() {
 
     let mut app = Router::new()
         .route("/api/health", get(health::health_check))
+        .route("/metrics", get(metrics::metrics_handler))
+        .route("/api/metrics", get(metrics::api_metrics_handler))
         .route("/api/simulation", post(simulation::simulation_handler))
         .route("/api/history", post(history::simulation_history_handler))
-        .with_state(pool);
+        .route("/api/rating", post(rating::rating_handler))
+        .route("/api/head-to-head", post(rating::head_to_head_handler))
+        .route(
+            "/api/calibrate-half-life",
+            post(calibration::calibrate_half_life_handler),
+        )
+        .with_state(state.clone());
 
     cfg_if! {
         if #[cfg(feature = "enable_cache")] {
@@ -93,7 +104,9 @@ async fn main() {
         }
     }
 
-    app = app.layer(middleware::from_fn(timer_middleware)).layer(cors);
+    app = app
+        .layer(middleware::from_fn_with_state(state, timer_middleware))
+        .layer(cors);
 
     let addr = format!("0.0.0.0:{}", port_num);
     println!("Server running on {}", addr);
@@ -0,0 +1,53 @@
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+
+use crate::utils::calibration;
+use crate::utils::database;
+use crate::utils::state::AppState;
+use crate::utils::types::{CalibrateHalfLifeRequest, CalibrateHalfLifeResponse};
+use crate::utils::validation::clean_and_validate_wca_id;
+
+pub async fn calibrate_half_life_handler(
+    State(state): State<AppState>,
+    Json(payload): Json<CalibrateHalfLifeRequest>,
+) -> impl IntoResponse {
+    if payload.competitor_ids.len() > 32 {
+        return (StatusCode::BAD_REQUEST, "Max 32 competitors").into_response();
+    }
+
+    let competitor_ids_upper: Vec<String> = match payload
+        .competitor_ids
+        .iter()
+        .map(|id| clean_and_validate_wca_id(id).ok_or_else(|| id.clone()))
+        .collect::<Result<Vec<String>, String>>()
+    {
+        Ok(ids) => ids,
+        Err(invalid_id) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                format!("Invalid competitor ID: {}", invalid_id),
+            )
+                .into_response();
+        }
+    };
+
+    let results = match database::fetch_competitor_results(
+        &state.pool,
+        &competitor_ids_upper,
+        &payload.event_id,
+        payload.start_date,
+        payload.end_date,
+    )
+    .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("DB Error (results): {}", e);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let grouped = database::group_results_by_date(results);
+    let half_life = calibration::calibrate_half_life(&grouped);
+
+    Json(CalibrateHalfLifeResponse { half_life }).into_response()
+}
@@ -1,11 +1,14 @@
 use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
 use chrono::{Months, NaiveDate};
-use sqlx::PgPool;
 use std::collections::HashMap;
+use std::sync::Arc;
 
+use crate::utils::cache;
 use crate::utils::competitor::Competitor;
 use crate::utils::database;
+use crate::utils::metrics;
 use crate::utils::simulation;
+use crate::utils::state::AppState;
 use crate::utils::types::{
     CompetitorHistoryStat, DatedCompetitionResult, EventType, HistoryPoint,
     SimulationHistoryRequest, SimulationHistoryResponse,
@@ -19,7 +22,7 @@ const HISTORY_STEPS: u32 = 12;
 const NUM_SIMULATIONS: u32 = 5_000;
 
 pub async fn simulation_history_handler(
-    State(pool): State<PgPool>,
+    State(state): State<AppState>,
     Json(payload): Json<SimulationHistoryRequest>,
 ) -> impl IntoResponse {
     // 1. Validation
@@ -76,13 +79,13 @@ pub async fn simulation_history_handler(
     // --- Data Fetching ---
     let (result_rows, name_rows) = tokio::join!(
         database::fetch_competitor_results(
-            &pool,
+            &state.pool,
             &competitor_ids_upper,
             &payload.event_id,
             fetch_start_limit,
             payload.end_date
         ),
-        database::fetch_competitor_names(&pool, &competitor_ids_upper)
+        database::fetch_competitor_names(&state.pool, &competitor_ids_upper)
     );
 
     let (all_results, names_map) = match (result_rows, name_rows) {
@@ -97,6 +100,22 @@ pub async fn simulation_history_handler(
         }
     };
 
+    let cache_key = cache::fingerprint(
+        &competitor_ids_upper,
+        &payload.event_id,
+        payload.start_date,
+        payload.end_date,
+        payload.half_life,
+        payload.include_dnf.unwrap_or(false),
+        None,
+        payload.target_win_chance_ci_half_width,
+        cache::data_version(&all_results),
+    );
+
+    if let Some(cached) = state.history_cache.get(&cache_key).await {
+        return Json(cached).into_response();
+    }
+
     // Group raw rows by Person -> Date -> Values
     let grouped_raw = database::group_results_by_date(all_results);
 
@@ -126,18 +145,27 @@ pub async fn simulation_history_handler(
                 let name = names_map.get(id).cloned().unwrap_or_else(|| id.clone());
 
                 // Create competitor
-                let comp = Competitor::new(name, id.clone(), dated_results, payload.half_life);
+                let comp = Competitor::new(
+                    name,
+                    id.clone(),
+                    dated_results,
+                    payload.half_life,
+                    event_type,
+                );
                 competitors.push(comp);
             }
 
+            metrics::record_competitor_count(competitors.len() as u64);
+
             // --- B. Run Simulation ---
             let include_dnf = payload.include_dnf.unwrap_or(false);
-            // Ensure run_simulations accepts the count param, or update this call
             let sim_results = simulation::run_simulations(
                 &competitors,
                 &event_type,
                 include_dnf,
                 NUM_SIMULATIONS,
+                payload.target_win_chance_ci_half_width,
+                None,
             );
 
             // --- C. Extract Stats ---
@@ -147,9 +175,11 @@ pub async fn simulation_history_handler(
                 .map(|(i, comp)| CompetitorHistoryStat {
                     id: comp.id.clone(),
                     name: comp.name.clone(),
-                    win_count: sim_results.win_counts[i],
-                    pod_count: sim_results.pod_counts[i],
-                    total_rank: sim_results.total_ranks[i],
+                    win_chance: sim_results.win_chance[i],
+                    win_chance_se: sim_results.win_chance_se[i],
+                    pod_chance: sim_results.pod_chance[i],
+                    pod_chance_se: sim_results.pod_chance_se[i],
+                    expected_rank: sim_results.expected_ranks[i],
                     sample_size: comp
                         .stats
                         .as_ref()
@@ -187,7 +217,11 @@ pub async fn simulation_history_handler(
 
     // Handle JoinHandle errors
     match history_response {
-        Ok(response) => Json(response).into_response(),
+        Ok(response) => {
+            let response = Arc::new(response);
+            state.history_cache.insert(cache_key, response.clone()).await;
+            Json(response).into_response()
+        }
         Err(e) => {
             eprintln!("Simulation task join error: {}", e);
             StatusCode::INTERNAL_SERVER_ERROR.into_response()
@@ -0,0 +1,30 @@
+use axum::{
+    extract::State,
+    http::{StatusCode, header},
+};
+
+use crate::utils::metrics;
+use crate::utils::state::AppState;
+
+/// Serve the accumulated latency histograms and counters in the Prometheus text exposition
+/// format, so an existing Prometheus scraper can compute p50/p99 latencies and request volume.
+pub async fn metrics_handler() -> (StatusCode, [(header::HeaderName, &'static str); 1], String) {
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        metrics::render_prometheus_text(),
+    )
+}
+
+/// Serve the per-route request/status counters and the request/simulation-compute latency
+/// histograms from the state-injected [`crate::utils::metrics::HttpMetricsRegistry`], in
+/// Prometheus text exposition format.
+pub async fn api_metrics_handler(
+    State(state): State<AppState>,
+) -> (StatusCode, [(header::HeaderName, &'static str); 1], String) {
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        state.http_metrics.render_prometheus_text(),
+    )
+}
@@ -0,0 +1,6 @@
+pub mod calibration;
+pub mod health;
+pub mod history;
+pub mod metrics;
+pub mod rating;
+pub mod simulation;
@@ -0,0 +1,134 @@
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use chrono::NaiveDate;
+use sqlx::PgPool;
+use std::collections::HashMap;
+
+use crate::utils::database;
+use crate::utils::rating::{self, Glicko2Rating};
+use crate::utils::state::AppState;
+use crate::utils::types::{
+    CompetitorRating, HeadToHeadRequest, HeadToHeadResult, RatingEndpointResults, RatingRequest,
+};
+use crate::utils::validation::clean_and_validate_wca_id;
+
+async fn fetch_field_ratings(
+    pool: &PgPool,
+    competitor_ids: &[String],
+    event_id: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<(HashMap<String, Glicko2Rating>, HashMap<String, String>), sqlx::Error> {
+    let (result_rows, name_rows) = tokio::join!(
+        database::fetch_competitor_results(pool, competitor_ids, event_id, start_date, end_date),
+        database::fetch_competitor_names(pool, competitor_ids)
+    );
+
+    let names_map: HashMap<String, String> = name_rows?.into_iter().collect();
+    let grouped = database::group_results_by_date(result_rows?);
+
+    Ok((rating::compute_field_ratings(&grouped), names_map))
+}
+
+fn format_rating(
+    id: &str,
+    names_map: &HashMap<String, String>,
+    rating: Glicko2Rating,
+) -> CompetitorRating {
+    CompetitorRating {
+        id: id.to_string(),
+        name: names_map.get(id).cloned().unwrap_or_else(|| id.to_string()),
+        rating: rating.rating,
+        rd: rating.rd,
+        volatility: rating.volatility,
+    }
+}
+
+pub async fn rating_handler(
+    State(state): State<AppState>,
+    Json(payload): Json<RatingRequest>,
+) -> impl IntoResponse {
+    if payload.competitor_ids.len() > 32 {
+        return (StatusCode::BAD_REQUEST, "Max 32 competitors").into_response();
+    }
+
+    let competitor_ids_upper: Vec<String> = match payload
+        .competitor_ids
+        .iter()
+        .map(|id| clean_and_validate_wca_id(id).ok_or_else(|| id.clone()))
+        .collect::<Result<Vec<String>, String>>()
+    {
+        Ok(ids) => ids,
+        Err(invalid_id) => {
+            let error_msg = format!("Invalid competitor ID: {}", invalid_id);
+            return (StatusCode::BAD_REQUEST, error_msg).into_response();
+        }
+    };
+
+    let (ratings, names_map) = match fetch_field_ratings(
+        &state.pool,
+        &competitor_ids_upper,
+        &payload.event_id,
+        payload.start_date,
+        payload.end_date,
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("DB Error (rating): {}", e);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let response = RatingEndpointResults {
+        ratings: competitor_ids_upper
+            .iter()
+            .map(|id| {
+                let rating = ratings.get(id).copied().unwrap_or_default();
+                format_rating(id, &names_map, rating)
+            })
+            .collect(),
+    };
+
+    Json(response).into_response()
+}
+
+pub async fn head_to_head_handler(
+    State(state): State<AppState>,
+    Json(payload): Json<HeadToHeadRequest>,
+) -> impl IntoResponse {
+    let (id_a, id_b) = match (
+        clean_and_validate_wca_id(&payload.competitor_a),
+        clean_and_validate_wca_id(&payload.competitor_b),
+    ) {
+        (Some(a), Some(b)) => (a, b),
+        _ => return (StatusCode::BAD_REQUEST, "Invalid competitor ID").into_response(),
+    };
+
+    let (ratings, names_map) = match fetch_field_ratings(
+        &state.pool,
+        &[id_a.clone(), id_b.clone()],
+        &payload.event_id,
+        payload.start_date,
+        payload.end_date,
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("DB Error (head-to-head): {}", e);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let rating_a = ratings.get(&id_a).copied().unwrap_or_default();
+    let rating_b = ratings.get(&id_b).copied().unwrap_or_default();
+
+    let response = HeadToHeadResult {
+        win_probability_a: rating::win_probability(&rating_a, &rating_b),
+        competitor_a: format_rating(&id_a, &names_map, rating_a),
+        competitor_b: format_rating(&id_b, &names_map, rating_b),
+    };
+
+    Json(response).into_response()
+}
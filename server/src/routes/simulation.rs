@@ -1,18 +1,21 @@
 use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
 use chrono::Days;
-use sqlx::PgPool;
 use std::collections::HashMap;
+use std::sync::Arc;
 
+use crate::utils::cache;
 use crate::utils::competitor::Competitor;
 use crate::utils::database;
+use crate::utils::metrics;
 use crate::utils::simulation;
+use crate::utils::state::AppState;
 use crate::utils::types::{EventType, SimulationRequest};
 use crate::utils::validation::clean_and_validate_wca_id;
 
 const SIMULATION_COUNT: u32 = 100_000; // Seems to run pretty fast for now, can tune down if needed
 
 pub async fn simulation_handler(
-    State(pool): State<PgPool>,
+    State(state): State<AppState>,
     Json(payload): Json<SimulationRequest>,
 ) -> impl IntoResponse {
     if payload.competitor_ids.len() > 32 {
@@ -60,13 +63,13 @@ pub async fn simulation_handler(
 
     let (result_rows, name_rows) = tokio::join!(
         database::fetch_competitor_results(
-            &pool,
+            &state.pool,
             &competitor_ids_upper,
             &payload.event_id,
             payload.start_date,
             payload.end_date
         ),
-        database::fetch_competitor_names(&pool, &competitor_ids_upper)
+        database::fetch_competitor_names(&state.pool, &competitor_ids_upper)
     );
 
     let (results, mut names_map) = match (result_rows, name_rows) {
@@ -84,6 +87,28 @@ pub async fn simulation_handler(
         }
     };
 
+    // Manual entered_times make the result request-specific, so those requests skip the cache
+    // entirely rather than being folded into the fingerprint.
+    let cache_key = payload.entered_times.is_none().then(|| {
+        cache::fingerprint(
+            &competitor_ids_upper,
+            &event_id,
+            payload.start_date,
+            payload.end_date,
+            payload.half_life,
+            payload.include_dnf.unwrap_or(false),
+            payload.seed,
+            payload.target_win_chance_ci_half_width,
+            cache::data_version(&results),
+        )
+    });
+
+    if let Some(key) = cache_key
+        && let Some(cached) = state.simulation_cache.get(&key).await
+    {
+        return Json(cached).into_response();
+    }
+
     let grouped = database::group_results_by_date(results);
     let mut raw_data = database::convert_to_dated_results(grouped);
 
@@ -99,7 +124,13 @@ pub async fn simulation_handler(
             }
         };
 
-        let mut comp = Competitor::new(competitor_name, id.clone(), results, payload.half_life);
+        let mut comp = Competitor::new(
+            competitor_name,
+            id.clone(),
+            results,
+            payload.half_life,
+            event_type,
+        );
 
         if let Some(entered) = &payload.entered_times
             && let Some(times) = entered.get(i)
@@ -110,11 +141,30 @@ pub async fn simulation_handler(
         competitors.push(comp);
     }
 
+    metrics::record_competitor_count(competitors.len() as u64);
+
     let include_dnf = payload.include_dnf.unwrap_or(false);
-    let results =
-        simulation::run_simulations(&competitors, &event_type, include_dnf, SIMULATION_COUNT);
 
-    let response_data =
-        simulation::format_results(competitors, results, matches!(event_type, EventType::Fmc));
+    let compute_start = std::time::Instant::now();
+    let results = simulation::run_simulations(
+        &competitors,
+        &event_type,
+        include_dnf,
+        SIMULATION_COUNT,
+        payload.target_win_chance_ci_half_width,
+        payload.seed,
+    );
+
+    let response_data = Arc::new(simulation::format_results(
+        competitors,
+        results,
+        matches!(event_type, EventType::Fmc),
+    ));
+    state.http_metrics.record_simulation_compute(compute_start.elapsed());
+
+    if let Some(key) = cache_key {
+        state.simulation_cache.insert(key, response_data.clone()).await;
+    }
+
     Json(response_data).into_response()
 }
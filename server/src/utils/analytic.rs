@@ -0,0 +1,341 @@
+//! Analytic (non-Monte-Carlo) win/podium/rank computation for small best-of-k fields.
+//!
+//! `run_simulations` normally estimates these by sampling, which costs sampling noise that only
+//! shrinks as `1/sqrt(N)`. For "best of k" formats the round result is just the min of `k` iid
+//! draws from a single fitted skew-normal, so the win/podium/rank distribution can instead be
+//! read directly off each competitor's CDF via numeric quadrature -- no sampling noise, and no
+//! need to spend millions of iterations on a small field.
+
+use std::collections::HashMap;
+
+use super::calc::{skewnorm_cdf, skewnorm_pdf};
+use super::competitor::Competitor;
+use super::constants::DNF_VALUE;
+use super::simulation::{num_solves, truncate_num, SimulationResults, HIST_INCLUDE_THRESHOLD};
+use super::types::{ConfidenceInterval, EventType, PercentileSummary};
+
+/// Grid resolution for the numeric quadrature below. Plausible-range width is chosen per field
+/// (wide enough to cover every competitor's distribution), so this is "adaptive" in range even
+/// though the step count is fixed.
+const GRID_POINTS: usize = 2000;
+const GRID_WIDTH_STDEVS: f32 = 6.0;
+
+/// The quadrature grid can't resolve probability mass finer than roughly one grid cell's worth
+/// out of `GRID_POINTS`. This is reported as a conservative (not statistically rigorous, unlike
+/// the Monte Carlo path's binomial/Wilson intervals) approximation-error band for win/podium
+/// chances, rather than a literal zero-width interval -- a true SE/CI of 0 would claim the
+/// estimate is infinitely precise, which it isn't; it's just not noise-driven.
+const GRID_PROB_HALF_WIDTH: f64 = 1.0 / GRID_POINTS as f64;
+
+fn approx_prob_ci(p: f64) -> ConfidenceInterval {
+    ConfidenceInterval {
+        lower: (p - GRID_PROB_HALF_WIDTH).max(0.0),
+        upper: (p + GRID_PROB_HALF_WIDTH).min(1.0),
+    }
+}
+
+/// Same grid-resolution-driven approximation error as [`approx_prob_ci`], scaled onto the
+/// 1..=n rank axis instead of a 0..1 probability. Not a standard error -- just the CI half-width
+/// -- hence the name avoiding `_se`.
+fn approx_rank_half_width(n: usize) -> f64 {
+    n as f64 * GRID_PROB_HALF_WIDTH
+}
+
+fn approx_rank_ci(mean: f64, n: usize) -> ConfidenceInterval {
+    let half = approx_rank_half_width(n);
+    ConfidenceInterval {
+        lower: (mean - half).max(1.0),
+        upper: (mean + half).min(n as f64),
+    }
+}
+
+/// Only "best of k" formats reduce to the min of `k` iid draws from a single skew-normal --
+/// Ao5/Mo3/Fmc average several draws together into a different distribution shape, and
+/// Multi-Blind isn't a single magnitude at all, so neither is modeled here.
+pub fn supports_analytic(event_type: &EventType) -> bool {
+    matches!(event_type, EventType::Bo3 | EventType::Bo5)
+}
+
+/// Exact (up to grid resolution) win/podium/rank/percentile statistics for `competitors`,
+/// replacing the Monte Carlo loop entirely. Returns `None` if any competitor lacks a fitted
+/// distribution or has a manual time entered (manual overrides aren't modeled analytically, so
+/// callers should fall back to the Monte Carlo path for those).
+pub fn compute_analytic(
+    competitors: &[Competitor],
+    event_type: &EventType,
+    include_dnf: bool,
+) -> Option<SimulationResults> {
+    let n = competitors.len();
+    if n == 0 {
+        return None;
+    }
+
+    if competitors
+        .iter()
+        .any(|c| c.stats.is_none() || !c.entered_results.is_empty())
+    {
+        return None;
+    }
+
+    let k = num_solves(*event_type) as i32;
+    let stats: Vec<_> = competitors.iter().map(|c| c.stats.as_ref().unwrap()).collect();
+
+    let lower = stats
+        .iter()
+        .map(|s| s.location - GRID_WIDTH_STDEVS * s.shape)
+        .fold(f32::INFINITY, f32::min);
+    let upper = stats
+        .iter()
+        .map(|s| s.location + GRID_WIDTH_STDEVS * s.shape)
+        .fold(f32::NEG_INFINITY, f32::max);
+    if !lower.is_finite() || !upper.is_finite() || upper <= lower {
+        return None;
+    }
+    let h = (upper - lower) / GRID_POINTS as f32;
+
+    // Per-competitor, per-grid-point: the single-draw density (scaled down by `1 - dnf_rate` when
+    // DNFs are included, since a DNF draw contributes no finite time), and the round-result (min of
+    // k draws) density/CDF derived from it.
+    let mut single_pdf = vec![vec![0f32; GRID_POINTS + 1]; n];
+    let mut round_pdf = vec![vec![0f32; GRID_POINTS + 1]; n];
+    let mut round_cdf = vec![vec![0f32; GRID_POINTS + 1]; n];
+
+    for (i, s) in stats.iter().enumerate() {
+        let survive = if include_dnf { 1.0 - s.dnf_rate } else { 1.0 };
+        for step in 0..=GRID_POINTS {
+            let t = lower + step as f32 * h;
+            let draw_pdf = skewnorm_pdf(t, s.location, s.shape, s.skew) * survive;
+            let draw_cdf = (skewnorm_cdf(t, s.location, s.shape, s.skew) * survive).clamp(0.0, 1.0);
+
+            single_pdf[i][step] = draw_pdf;
+            round_cdf[i][step] = 1.0 - (1.0 - draw_cdf).powi(k);
+            round_pdf[i][step] = k as f32 * draw_pdf * (1.0 - draw_cdf).powi(k - 1);
+        }
+    }
+
+    // For each competitor i and grid cell, accumulate the probability that exactly r of the
+    // *other* competitors finish before i via a Poisson-binomial built by convolution -- this
+    // directly gives i's full rank distribution, not just win/podium.
+    let mut rank_mass = vec![vec![0f64; n]; n];
+
+    for i in 0..n {
+        for step in 0..GRID_POINTS {
+            let g_i = 0.5 * (round_pdf[i][step] + round_pdf[i][step + 1]) as f64 * h as f64;
+            if g_i <= 0.0 {
+                continue;
+            }
+
+            let mut dist = vec![1.0f64];
+            for (j, _) in stats.iter().enumerate() {
+                if j == i {
+                    continue;
+                }
+                let p = (0.5 * (round_cdf[j][step] + round_cdf[j][step + 1]) as f64).clamp(0.0, 1.0);
+                let mut next = vec![0.0; dist.len() + 1];
+                for (r, &mass) in dist.iter().enumerate() {
+                    next[r] += mass * (1.0 - p);
+                    next[r + 1] += mass * p;
+                }
+                dist = next;
+            }
+
+            for (r, &mass) in dist.iter().enumerate() {
+                rank_mass[i][r] += g_i * mass;
+            }
+        }
+    }
+
+    let pod_size = 3.min(n);
+    let mut win_chance = vec![0f64; n];
+    let mut pod_chance = vec![0f64; n];
+    let mut expected_ranks = vec![0f64; n];
+    let mut rank_dists = vec![vec![0f64; n]; n];
+
+    for i in 0..n {
+        let mut dist = rank_mass[i].clone();
+        // Competitor i's own DNF rounds (no finite round result at all) fold into the worst
+        // possible placement, matching the Monte Carlo convention of sorting DNF last.
+        let dnf_mass = (1.0 - dist.iter().sum::<f64>()).max(0.0);
+        dist[n - 1] += dnf_mass;
+
+        win_chance[i] = dist[0];
+        pod_chance[i] = dist[..pod_size].iter().sum();
+        expected_ranks[i] = dist.iter().enumerate().map(|(r, &m)| (r + 1) as f64 * m).sum();
+        rank_dists[i] = dist;
+    }
+
+    let win_chance_ci = win_chance.iter().map(|&p| approx_prob_ci(p)).collect();
+    let pod_chance_ci = pod_chance.iter().map(|&p| approx_prob_ci(p)).collect();
+    let expected_rank_ci = expected_ranks
+        .iter()
+        .map(|&mean| approx_rank_ci(mean, n))
+        .collect();
+
+    let single_percentiles = (0..n)
+        .map(|i| grid_percentiles(&single_pdf[i], lower, h))
+        .collect();
+    let average_percentiles = (0..n)
+        .map(|i| grid_percentiles(&round_pdf[i], lower, h))
+        .collect();
+
+    let hist_singles = (0..n)
+        .map(|i| bucket_histogram(&single_pdf[i], lower, h, matches!(event_type, EventType::Fmc)))
+        .collect();
+    let hist_averages = (0..n)
+        .map(|i| bucket_histogram(&round_pdf[i], lower, h, matches!(event_type, EventType::Fmc)))
+        .collect();
+
+    Some(SimulationResults {
+        win_chance,
+        // `*_se` is documented (see `CompetitorSimulationResult`) as the Monte Carlo binomial
+        // standard error -- this path doesn't sample, so there's no such quantity to report, and
+        // stuffing the grid-resolution error into the same field would silently redefine it.
+        // `*_ci` still carries that grid-resolution approximation band (via `approx_prob_ci` /
+        // `approx_rank_ci` above), just not labeled as a standard error.
+        win_chance_se: vec![0.0; n],
+        win_chance_ci,
+        pod_chance,
+        pod_chance_se: vec![0.0; n],
+        pod_chance_ci,
+        expected_ranks,
+        expected_rank_se: vec![0.0; n],
+        expected_rank_ci,
+        rank_dists,
+        hist_singles,
+        hist_averages,
+        single_percentiles,
+        average_percentiles,
+        // The analytic path has no per-sample stream to accumulate moments over, just the
+        // resulting grid distribution.
+        single_moments: vec![None; n],
+        average_moments: vec![None; n],
+        // There's no iteration count to report for an analytic result; the grid resolution
+        // above is the only source of approximation error.
+        simulation_count: 0,
+    })
+}
+
+/// p5/p50/p90/p95 read off the cumulative integral of `pdf_vals` over the grid, conditioned on
+/// the distribution's total mass (which is less than 1 when a DNF rate was folded in), matching
+/// [`super::quantile::QuantileSummary`]'s convention of only ever tracking non-DNF values.
+fn grid_percentiles(pdf_vals: &[f32], lower: f32, h: f32) -> PercentileSummary {
+    let total: f64 = trapezoid_total(pdf_vals, h);
+    if total <= 0.0 {
+        return PercentileSummary {
+            p5: None,
+            p50: None,
+            p90: None,
+            p95: None,
+        };
+    }
+
+    let find = |phi: f64| -> Option<i32> {
+        let target = phi * total;
+        let mut cum = 0.0;
+        for step in 0..pdf_vals.len() - 1 {
+            let mass = 0.5 * (pdf_vals[step] + pdf_vals[step + 1]) as f64 * h as f64;
+            if cum + mass >= target {
+                let t = lower + (step as f32 + 0.5) * h;
+                return Some(t.round() as i32);
+            }
+            cum += mass;
+        }
+        Some((lower + (pdf_vals.len() as f32 - 0.5) * h).round() as i32)
+    };
+
+    PercentileSummary {
+        p5: find(0.05),
+        p50: find(0.50),
+        p90: find(0.90),
+        p95: find(0.95),
+    }
+}
+
+fn trapezoid_total(vals: &[f32], h: f32) -> f64 {
+    vals.windows(2)
+        .map(|pair| 0.5 * (pair[0] + pair[1]) as f64 * h as f64)
+        .sum()
+}
+
+/// Discretize `pdf_vals` onto the same bucket resolution `generate_histogram` uses, producing
+/// the same `count * scale / simulation_count` style percentage values the Monte Carlo path
+/// would, but computed directly from the density's mass in each bucket rather than sampled
+/// counts.
+fn bucket_histogram(pdf_vals: &[f32], lower: f32, h: f32, is_fmc: bool) -> HashMap<i32, f64> {
+    let mut mass_by_bucket: HashMap<i32, f64> = HashMap::new();
+
+    for step in 0..pdf_vals.len() - 1 {
+        let mass = 0.5 * (pdf_vals[step] + pdf_vals[step + 1]) as f64 * h as f64;
+        if mass <= 0.0 {
+            continue;
+        }
+        let midpoint = (lower + (step as f32 + 0.5) * h) as i32;
+        if midpoint <= 0 || midpoint >= DNF_VALUE {
+            continue;
+        }
+        let bucket = truncate_num(midpoint, is_fmc);
+        *mass_by_bucket.entry(bucket).or_default() += mass;
+    }
+
+    mass_by_bucket
+        .into_iter()
+        .filter(|&(_, mass)| mass >= HIST_INCLUDE_THRESHOLD)
+        .map(|(bucket, mass)| (bucket, mass * 100.0))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn approx_prob_ci_is_centered_and_clamped() {
+        let ci = approx_prob_ci(0.5);
+        assert!((ci.lower - (0.5 - GRID_PROB_HALF_WIDTH)).abs() < 1e-12);
+        assert!((ci.upper - (0.5 + GRID_PROB_HALF_WIDTH)).abs() < 1e-12);
+
+        // Near the boundaries, the interval clamps to [0, 1] rather than going negative/>1.
+        assert_eq!(approx_prob_ci(0.0).lower, 0.0);
+        assert_eq!(approx_prob_ci(1.0).upper, 1.0);
+    }
+
+    #[test]
+    fn approx_rank_ci_widens_with_field_size_and_clamps_to_valid_ranks() {
+        let small = approx_rank_ci(2.0, 4);
+        let large = approx_rank_ci(2.0, 32);
+        let small_width = small.upper - small.lower;
+        let large_width = large.upper - large.lower;
+        assert!(large_width > small_width);
+
+        // A mean near the edge of [1, n] still clamps within valid rank bounds.
+        let edge = approx_rank_ci(1.0, 4);
+        assert!(edge.lower >= 1.0);
+    }
+
+    #[test]
+    fn trapezoid_total_integrates_a_constant_density() {
+        // A flat pdf of height 1 over 10 unit steps integrates to 10.
+        let vals = vec![1.0f32; 11];
+        let total = trapezoid_total(&vals, 1.0);
+        assert!((total - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn grid_percentiles_returns_none_for_zero_mass() {
+        let vals = vec![0.0f32; 11];
+        let summary = grid_percentiles(&vals, 0.0, 1.0);
+        assert_eq!(summary.p5, None);
+        assert_eq!(summary.p50, None);
+        assert_eq!(summary.p90, None);
+        assert_eq!(summary.p95, None);
+    }
+
+    #[test]
+    fn grid_percentiles_finds_median_of_a_uniform_density() {
+        // Flat density over [0, 100], so p50 should land near the midpoint.
+        let vals = vec![1.0f32; 101];
+        let summary = grid_percentiles(&vals, 0.0, 1.0);
+        let p50 = summary.p50.expect("non-zero mass should yield a p50");
+        assert!((p50 - 50).abs() <= 1);
+    }
+}
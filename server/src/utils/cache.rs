@@ -0,0 +1,72 @@
+use std::{
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::Arc,
+    time::Duration,
+};
+
+use chrono::NaiveDate;
+use moka::future::Cache;
+
+use crate::utils::database::CompetitorRow;
+
+const CACHE_TTL_SECS: u64 = 60 * 60;
+const CACHE_MAX_ITEMS: u64 = 10_000;
+
+/// A bounded, TTL'd in-memory cache from a fingerprint (see [`fingerprint`]) to a shared,
+/// already-computed response. Entries age out on their own, so a stale result is never served
+/// past `CACHE_TTL_SECS` even if its data-version token is somehow never invalidated.
+pub type SimulationCache<T> = Cache<u64, Arc<T>>;
+
+pub fn new_simulation_cache<T: Send + Sync + 'static>() -> SimulationCache<T> {
+    Cache::builder()
+        .max_capacity(CACHE_MAX_ITEMS)
+        .time_to_live(Duration::from_secs(CACHE_TTL_SECS))
+        .build()
+}
+
+/// The newest `competition_date` seen across a set of fetched rows, used as a cheap
+/// "data version" token: the WCA results database only ever grows, so a cache entry fingerprinted
+/// with this token is automatically invalidated the moment a newer result is added for the same
+/// query. Falls back to `NaiveDate::MIN` when `rows` is empty (no results yet for this query).
+pub fn data_version(rows: &[CompetitorRow]) -> NaiveDate {
+    rows.iter()
+        .map(|row| row.competition_date)
+        .max()
+        .unwrap_or(NaiveDate::MIN)
+}
+
+/// Build a stable fingerprint of a simulation query's normalized inputs plus a `data_version`
+/// token, suitable as a [`SimulationCache`] key. Competitor IDs are sorted first so that
+/// requests differing only in ID order hit the same cache entry. `seed` is folded in so a
+/// seeded "scenario" request never collides with (or is ever served by) the unseeded default.
+#[allow(clippy::too_many_arguments)]
+pub fn fingerprint(
+    competitor_ids: &[String],
+    event_id: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    half_life: f32,
+    include_dnf: bool,
+    seed: Option<u64>,
+    target_win_chance_ci_half_width: Option<f64>,
+    data_version: NaiveDate,
+) -> u64 {
+    let mut sorted_ids: Vec<&str> = competitor_ids.iter().map(String::as_str).collect();
+    sorted_ids.sort_unstable();
+
+    let mut hasher = DefaultHasher::new();
+    sorted_ids.hash(&mut hasher);
+    event_id.hash(&mut hasher);
+    start_date.hash(&mut hasher);
+    end_date.hash(&mut hasher);
+    half_life.to_bits().hash(&mut hasher);
+    include_dnf.hash(&mut hasher);
+    seed.hash(&mut hasher);
+    // A different adaptive-stopping tolerance can cut a seeded run short at a different
+    // simulation_count, so it has to be part of the key alongside the seed itself.
+    target_win_chance_ci_half_width
+        .map(f64::to_bits)
+        .hash(&mut hasher);
+    data_version.hash(&mut hasher);
+    hasher.finish()
+}
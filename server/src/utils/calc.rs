@@ -1,5 +1,14 @@
 use std::f32::consts::PI;
 
+// Moment-of-moments skewness is only defined for |skew| below this bound (the skew-normal
+// distribution's skewness asymptotes to it as alpha -> +/-infinity), so the moment estimate is
+// clamped here before it's used as the MLE refinement's starting point.
+const MAX_SKEW_LIMIT: f32 = 0.995;
+
+// Bounded Nelder-Mead settings for the maximum-likelihood refinement below.
+const MLE_MAX_ITERS: usize = 200;
+const MLE_INIT_STEP: f32 = 0.1;
+
 pub fn calc_weighted_stats(data: &[(i32, f32)]) -> (f32, f32, f32) {
     if data.is_empty() {
         return (0.0, 0.0, 0.0);
@@ -28,7 +37,8 @@ pub fn calc_weighted_stats(data: &[(i32, f32)]) -> (f32, f32, f32) {
     (mean, variance, variance.sqrt())
 }
 
-pub fn fit_weighted_skewnorm(data: &[(i32, f32)]) -> (f32, f32, f32) {
+/// Method-of-moments skew-normal fit, used as the MLE refinement's starting point below.
+fn fit_weighted_skewnorm_moments(data: &[(i32, f32)]) -> (f32, f32, f32) {
     let (mean, variance, stdev) = calc_weighted_stats(data);
     if stdev == 0.0 {
         return (0.0, 1.0, mean);
@@ -42,13 +52,14 @@ pub fn fit_weighted_skewnorm(data: &[(i32, f32)]) -> (f32, f32, f32) {
         / total_weight;
 
     // Constants for skew normal approximation
-    let max_skew = 0.995 * ((4.0 - PI).sqrt() * (2.0 / PI).sqrt() * (1.0 - 2.0 / PI).powf(-1.5));
+    let max_skew =
+        MAX_SKEW_LIMIT * ((4.0 - PI).sqrt() * (2.0 / PI).sqrt() * (1.0 - 2.0 / PI).powf(-1.5));
     let bounded_skew = weighted_skewness.clamp(-max_skew, max_skew);
 
     let delta_term = (PI / 2.0) * bounded_skew.abs().powf(2.0 / 3.0)
         / (bounded_skew.abs().powf(2.0 / 3.0) + ((4.0 - PI) / 2.0).powf(2.0 / 3.0));
 
-    let delta = bounded_skew.signum() * delta_term.sqrt().clamp(-0.995, 0.995);
+    let delta = bounded_skew.signum() * delta_term.sqrt().clamp(-MAX_SKEW_LIMIT, MAX_SKEW_LIMIT);
     let alpha = delta / (1.0 - delta.powi(2)).sqrt();
     let omega = (variance / (1.0 - 2.0 * delta.powi(2) / PI)).sqrt();
     let xi = mean - omega * delta * (2.0 / PI).sqrt();
@@ -56,6 +67,181 @@ pub fn fit_weighted_skewnorm(data: &[(i32, f32)]) -> (f32, f32, f32) {
     (alpha, omega, xi)
 }
 
+/// Standard normal PDF.
+fn std_normal_pdf(x: f32) -> f32 {
+    (-0.5 * x * x).exp() / (2.0 * PI).sqrt()
+}
+
+/// Standard normal CDF, via the Abramowitz-Stegun 7.1.26 approximation (max error ~1.5e-7).
+fn std_normal_cdf(x: f32) -> f32 {
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let x = x.abs();
+
+    let a1 = 0.254829592;
+    let a2 = -0.284496736;
+    let a3 = 1.421413741;
+    let a4 = -1.453152027;
+    let a5 = 1.061405429;
+    let p = 0.3275911;
+
+    let t = 1.0 / (1.0 + p * x);
+    let poly = ((((a5 * t + a4) * t + a3) * t + a2) * t + a1) * t;
+    let erf = 1.0 - poly * (-x * x).exp();
+
+    0.5 * (1.0 + sign * erf)
+}
+
+/// Skew-normal PDF `f(x) = 2/omega * phi((x-xi)/omega) * Phi(alpha*(x-xi)/omega)`.
+pub fn skewnorm_pdf(x: f32, xi: f32, omega: f32, alpha: f32) -> f32 {
+    let z = (x - xi) / omega;
+    (2.0 / omega) * std_normal_pdf(z) * std_normal_cdf(alpha * z)
+}
+
+/// Skew-normal CDF, via trapezoid-rule quadrature of [`skewnorm_pdf`] from
+/// `xi - width` up to `x` (the density is negligible below `xi - width` for `width` in terms of
+/// `omega`, so this is accurate to the same tolerance as the step count implies).
+pub fn skewnorm_cdf(x: f32, xi: f32, omega: f32, alpha: f32) -> f32 {
+    const LOWER_WIDTH: f32 = 12.0;
+    const STEPS: u32 = 400;
+
+    let lower = xi - LOWER_WIDTH * omega;
+    if x <= lower {
+        return 0.0;
+    }
+
+    let h = (x - lower) / STEPS as f32;
+    let mut total = 0.0;
+    for i in 0..STEPS {
+        let t0 = lower + i as f32 * h;
+        let t1 = t0 + h;
+        total += 0.5 * (skewnorm_pdf(t0, xi, omega, alpha) + skewnorm_pdf(t1, xi, omega, alpha)) * h;
+    }
+    total.clamp(0.0, 1.0)
+}
+
+/// Weighted log-likelihood of a skew-normal(alpha, omega, xi) fit over `data`, per the density
+/// `2/omega * phi((x-xi)/omega) * Phi(alpha*(x-xi)/omega)`. Returns `f32::NEG_INFINITY` for an
+/// invalid (non-positive) scale, so a bounded optimizer can reject it outright.
+fn weighted_log_likelihood(params: (f32, f32, f32), data: &[(i32, f32)]) -> f32 {
+    let (alpha, omega, xi) = params;
+    if omega <= 0.0 || !omega.is_finite() {
+        return f32::NEG_INFINITY;
+    }
+
+    data.iter()
+        .map(|&(val, w)| {
+            let z = (val as f32 - xi) / omega;
+            let cdf_term = std_normal_cdf(alpha * z).max(f32::MIN_POSITIVE);
+            w * (2.0_f32.ln() - omega.ln() + std_normal_pdf(z).max(f32::MIN_POSITIVE).ln() + cdf_term.ln())
+        })
+        .sum()
+}
+
+/// Refine `(alpha, omega, xi)` by maximizing `weighted_log_likelihood` with a bounded Nelder-Mead
+/// simplex search, seeded from the method-of-moments estimate. Falls back to that estimate
+/// whenever the optimizer can't find a better point (degenerate data, too few samples, etc.).
+fn refine_skewnorm_mle(moment_estimate: (f32, f32, f32), data: &[(i32, f32)]) -> (f32, f32, f32) {
+    let eval = |p: (f32, f32, f32)| weighted_log_likelihood(p, data);
+
+    let (a0, o0, x0) = moment_estimate;
+    let step = |v: f32, s: f32| if v == 0.0 { s } else { v * (1.0 + s) };
+
+    // Simplex of 4 points in (alpha, omega, xi) space, seeded around the moment estimate.
+    let mut simplex = [
+        (a0, o0, x0),
+        (step(a0, MLE_INIT_STEP), o0, x0),
+        (a0, step(o0, MLE_INIT_STEP).max(f32::MIN_POSITIVE), x0),
+        (a0, o0, step(x0, MLE_INIT_STEP)),
+    ];
+    let mut scores: Vec<f32> = simplex.iter().map(|&p| eval(p)).collect();
+
+    for _ in 0..MLE_MAX_ITERS {
+        // Order by descending likelihood: best, second-worst, worst.
+        let mut order: Vec<usize> = (0..4).collect();
+        order.sort_by(|&i, &j| scores[j].total_cmp(&scores[i]));
+        let (best, second_worst, worst) = (order[0], order[2], order[3]);
+
+        let centroid = {
+            let others: Vec<(f32, f32, f32)> =
+                order[..3].iter().map(|&i| simplex[i]).collect();
+            let n = others.len() as f32;
+            (
+                others.iter().map(|p| p.0).sum::<f32>() / n,
+                others.iter().map(|p| p.1).sum::<f32>() / n,
+                others.iter().map(|p| p.2).sum::<f32>() / n,
+            )
+        };
+
+        let reflect = |c: (f32, f32, f32), w: (f32, f32, f32), factor: f32| {
+            (
+                c.0 + factor * (c.0 - w.0),
+                (c.1 + factor * (c.1 - w.1)).max(f32::MIN_POSITIVE),
+                c.2 + factor * (c.2 - w.2),
+            )
+        };
+
+        let worst_point = simplex[worst];
+        let reflected = reflect(centroid, worst_point, 1.0);
+        let reflected_score = eval(reflected);
+
+        if reflected_score > scores[second_worst] && reflected_score <= scores[best] {
+            simplex[worst] = reflected;
+            scores[worst] = reflected_score;
+        } else if reflected_score > scores[best] {
+            let expanded = reflect(centroid, worst_point, 2.0);
+            let expanded_score = eval(expanded);
+            if expanded_score > reflected_score {
+                simplex[worst] = expanded;
+                scores[worst] = expanded_score;
+            } else {
+                simplex[worst] = reflected;
+                scores[worst] = reflected_score;
+            }
+        } else {
+            let contracted = reflect(centroid, worst_point, -0.5);
+            let contracted_score = eval(contracted);
+            if contracted_score > scores[worst] {
+                simplex[worst] = contracted;
+                scores[worst] = contracted_score;
+            } else {
+                // Shrink the whole simplex toward the best point.
+                let best_point = simplex[best];
+                for i in 0..4 {
+                    if i == best {
+                        continue;
+                    }
+                    simplex[i] = (
+                        best_point.0 + 0.5 * (simplex[i].0 - best_point.0),
+                        (best_point.1 + 0.5 * (simplex[i].1 - best_point.1)).max(f32::MIN_POSITIVE),
+                        best_point.2 + 0.5 * (simplex[i].2 - best_point.2),
+                    );
+                    scores[i] = eval(simplex[i]);
+                }
+            }
+        }
+    }
+
+    let best_idx = (0..4)
+        .max_by(|&i, &j| scores[i].total_cmp(&scores[j]))
+        .expect("simplex always has 4 points");
+
+    if scores[best_idx] > eval(moment_estimate) {
+        simplex[best_idx]
+    } else {
+        moment_estimate
+    }
+}
+
+pub fn fit_weighted_skewnorm(data: &[(i32, f32)]) -> (f32, f32, f32) {
+    let moment_estimate = fit_weighted_skewnorm_moments(data);
+
+    if data.len() < 3 {
+        return moment_estimate;
+    }
+
+    refine_skewnorm_mle(moment_estimate, data)
+}
+
 pub fn trim_outliers(data: Vec<(i32, f32)>, mean: f32, stdev: f32) -> Vec<(i32, f32)> {
     let threshold = (mean + stdev * 2.0) as i32;
     data.into_iter()
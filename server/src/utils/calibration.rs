@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+
+use super::calc;
+
+/// Search range for the calibrated half-life, in days.
+const HALF_LIFE_MIN: f32 = 30.0;
+const HALF_LIFE_MAX: f32 = 730.0;
+
+const GOLDEN_SECTION_TOLERANCE: f32 = 1.0;
+const GOLDEN_SECTION_MAX_ITERS: usize = 100;
+// (sqrt(5) - 1) / 2
+const INV_GOLDEN: f32 = 0.618_034;
+
+/// Most recent solves per competitor held out as the validation set.
+const VALIDATION_SOLVE_COUNT: usize = 5;
+/// Minimum non-DNF solves required on the training side for a competitor to contribute a score.
+const MIN_TRAINING_SOLVES: usize = 10;
+
+/// Find the exponential-decay `half_life` (in days) that maximizes total held-out log-likelihood
+/// across every competitor in `grouped`, via golden-section search over
+/// `[HALF_LIFE_MIN, HALF_LIFE_MAX]`.
+///
+/// For each candidate half-life, a competitor's most recent [`VALIDATION_SOLVE_COUNT`] solves are
+/// held out; the rest are weighted by `0.5^(days_since/half_life)` and fit via
+/// [`calc::fit_weighted_skewnorm`], and the fit is scored by the sum of skew-normal log-densities
+/// of the held-out solves. DNFs are excluded from both the fit and the score. Competitors without
+/// enough training or validation solves are skipped entirely.
+pub fn calibrate_half_life(grouped: &HashMap<String, HashMap<NaiveDate, Vec<i32>>>) -> f32 {
+    let splits: Vec<(Vec<(i32, i32)>, Vec<i32>)> = grouped
+        .values()
+        .filter_map(|results| split_train_validation(results))
+        .collect();
+
+    if splits.is_empty() {
+        return (HALF_LIFE_MIN + HALF_LIFE_MAX) / 2.0;
+    }
+
+    golden_section_search(HALF_LIFE_MIN, HALF_LIFE_MAX, |half_life| {
+        total_log_likelihood(&splits, half_life)
+    })
+}
+
+/// Split one competitor's dated results into a training set (paired with its days-since-cutoff,
+/// for recency weighting) and a validation set of the non-DNF solves among the most recent
+/// [`VALIDATION_SOLVE_COUNT`] solves. Returns `None` if either side ends up without enough solves.
+fn split_train_validation(
+    results: &HashMap<NaiveDate, Vec<i32>>,
+) -> Option<(Vec<(i32, i32)>, Vec<i32>)> {
+    let mut flat: Vec<(NaiveDate, i32)> = results
+        .iter()
+        .flat_map(|(&date, times)| times.iter().map(move |&t| (date, t)))
+        .collect();
+    flat.sort_by_key(|&(date, _)| date);
+
+    if flat.len() <= VALIDATION_SOLVE_COUNT {
+        return None;
+    }
+
+    let split_idx = flat.len() - VALIDATION_SOLVE_COUNT;
+    let (train_raw, validation_raw) = flat.split_at(split_idx);
+
+    let validation: Vec<i32> = validation_raw
+        .iter()
+        .map(|&(_, t)| t)
+        .filter(|&t| t > 0)
+        .collect();
+    if validation.is_empty() {
+        return None;
+    }
+
+    // Days since the start of the validation window, so training weights stay comparable across
+    // competitors regardless of when their most recent solve happened to land.
+    let cutoff_date = validation_raw[0].0;
+    let train: Vec<(i32, i32)> = train_raw
+        .iter()
+        .map(|&(date, t)| ((cutoff_date - date).num_days() as i32, t))
+        .collect();
+
+    let non_dnf_train = train.iter().filter(|&&(_, t)| t > 0).count();
+    if non_dnf_train < MIN_TRAINING_SOLVES {
+        return None;
+    }
+
+    Some((train, validation))
+}
+
+fn total_log_likelihood(splits: &[(Vec<(i32, i32)>, Vec<i32>)], half_life: f32) -> f64 {
+    splits
+        .iter()
+        .filter_map(|(train, validation)| score_competitor(train, validation, half_life))
+        .sum()
+}
+
+/// Fit a skew-normal to `train` weighted by `half_life`, then score it as the sum of log-densities
+/// of `validation`. Returns `None` if `train` has fewer than `MIN_TRAINING_SOLVES` non-DNF solves
+/// (splits from `split_train_validation` already have at least that many).
+fn score_competitor(train: &[(i32, i32)], validation: &[i32], half_life: f32) -> Option<f64> {
+    let decay_rate = std::f32::consts::LN_2 / half_life;
+    let weighted: Vec<(i32, f32)> = train
+        .iter()
+        .filter(|&&(_, value)| value > 0)
+        .map(|&(days_since, value)| (value, (-decay_rate * days_since as f32).exp()))
+        .collect();
+
+    if weighted.len() < MIN_TRAINING_SOLVES {
+        return None;
+    }
+
+    let (mean, _, stdev) = calc::calc_weighted_stats(&weighted);
+    let trimmed = calc::trim_outliers(weighted, mean, stdev);
+    let (skew, shape, location) = calc::fit_weighted_skewnorm(&trimmed);
+
+    Some(
+        validation
+            .iter()
+            .map(|&value| {
+                calc::skewnorm_pdf(value as f32, location, shape, skew)
+                    .max(f32::MIN_POSITIVE)
+                    .ln() as f64
+            })
+            .sum(),
+    )
+}
+
+/// Golden-section search for the `x` in `[lo, hi]` that maximizes `f`.
+fn golden_section_search(mut lo: f32, mut hi: f32, f: impl Fn(f32) -> f64) -> f32 {
+    let mut x1 = hi - INV_GOLDEN * (hi - lo);
+    let mut x2 = lo + INV_GOLDEN * (hi - lo);
+    let mut f1 = f(x1);
+    let mut f2 = f(x2);
+
+    for _ in 0..GOLDEN_SECTION_MAX_ITERS {
+        if (hi - lo) < GOLDEN_SECTION_TOLERANCE {
+            break;
+        }
+
+        if f1 > f2 {
+            // Maximum lies in [lo, x2].
+            hi = x2;
+            x2 = x1;
+            f2 = f1;
+            x1 = hi - INV_GOLDEN * (hi - lo);
+            f1 = f(x1);
+        } else {
+            // Maximum lies in [x1, hi].
+            lo = x1;
+            x1 = x2;
+            f1 = f2;
+            x2 = lo + INV_GOLDEN * (hi - lo);
+            f2 = f(x2);
+        }
+    }
+
+    (lo + hi) / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn golden_section_search_finds_parabola_maximum() {
+        // f(x) = -(x - 42)^2, maximized at x = 42.
+        let x = golden_section_search(0.0, 100.0, |x| -((x - 42.0) as f64).powi(2));
+        assert!((x - 42.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn calibrate_half_life_falls_back_to_midpoint_when_no_splits() {
+        let grouped: HashMap<String, HashMap<NaiveDate, Vec<i32>>> = HashMap::new();
+        let half_life = calibrate_half_life(&grouped);
+        assert_eq!(half_life, (HALF_LIFE_MIN + HALF_LIFE_MAX) / 2.0);
+    }
+
+    #[test]
+    fn split_train_validation_rejects_too_few_solves() {
+        let mut results = HashMap::new();
+        let base = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        // Fewer solves than VALIDATION_SOLVE_COUNT, so there's nothing left to train on.
+        results.insert(base, vec![1000, 1100]);
+        assert!(split_train_validation(&results).is_none());
+    }
+
+    #[test]
+    fn split_train_validation_splits_most_recent_as_validation() {
+        let base = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let mut results = HashMap::new();
+        // MIN_TRAINING_SOLVES (10) training solves on day 0, plus VALIDATION_SOLVE_COUNT (5)
+        // validation solves a day later, which should land on the validation side.
+        results.insert(base, vec![1000; MIN_TRAINING_SOLVES]);
+        results.insert(
+            base + chrono::Duration::days(1),
+            vec![1000; VALIDATION_SOLVE_COUNT],
+        );
+
+        let (train, validation) = split_train_validation(&results).unwrap();
+        assert_eq!(train.len(), MIN_TRAINING_SOLVES);
+        assert_eq!(validation.len(), VALIDATION_SOLVE_COUNT);
+        // Training solves are a day before the validation cutoff.
+        assert!(train.iter().all(|&(days_since, _)| days_since == 1));
+    }
+}
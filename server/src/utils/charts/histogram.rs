@@ -52,23 +52,30 @@ impl Iterator for HistogramKeys {
         }
 
         let yield_val = self.count;
-        let decimals = self.count % 100;
-
-        self.count = match (self.is_fmc, self.is_average, decimals) {
-            // FMC & Average: Steps of 33/34/33 to approximate 100/3
-            (true, true, 0) => self.count + 33,
-            (true, true, 33) => self.count + 34,
-            (true, true, 67) => self.count + 33,
-            // FMC & Single: Step 100 (1 move)
-            (true, false, _) => self.count + 100,
-            // Standard Time: Step 10
-            _ => self.count + 10,
-        };
+        self.count += bin_step(self.count, self.is_fmc, self.is_average);
 
         Some(yield_val)
     }
 }
 
+/// Step from `key` to the next histogram bin boundary. Pulled out of [`HistogramKeys::next`] so
+/// callers that need to interpolate within a single bin (e.g. a quantile lookup) stay in sync
+/// with the bucket width the histogram was actually built with.
+pub(crate) fn bin_step(key: i32, is_fmc: bool, is_average: bool) -> i32 {
+    let decimals = key % 100;
+
+    match (is_fmc, is_average, decimals) {
+        // FMC & Average: Steps of 33/34/33 to approximate 100/3
+        (true, true, 0) => 33,
+        (true, true, 33) => 34,
+        (true, true, 67) => 33,
+        // FMC & Single: Step 100 (1 move)
+        (true, false, _) => 100,
+        // Standard Time: Step 10
+        _ => 10,
+    }
+}
+
 pub fn create_individual_histogram_chart(
     singles: &HistogramData,
     averages: &HistogramData,
@@ -2,7 +2,9 @@ pub mod builder;
 pub mod histogram;
 pub mod models;
 pub mod rank;
+pub mod trajectory;
 
 pub use histogram::*;
 pub use models::*;
 pub use rank::*;
+pub use trajectory::*;
@@ -2,6 +2,15 @@ use itertools::Itertools;
 use serde::Serialize;
 use std::collections::HashMap;
 
+use crate::utils::stats::{standard_error, wilson_interval};
+use crate::utils::types::ConfidenceInterval;
+use crate::utils::wca::DNF_VALUE;
+
+use super::histogram::bin_step;
+
+// z-score for a 95% confidence interval.
+const CONFIDENCE_Z: f64 = 1.96;
+
 #[derive(Serialize)]
 pub struct ChartData {
     pub labels: Vec<String>,
@@ -28,6 +37,14 @@ impl HistogramAccumulator {
         *self.counts.entry(key).or_default() += 1;
     }
 
+    /// Fold another shard's counts into this one, entry-wise. Associative and order-independent,
+    /// so shards can be merged in any order once a parallel run has produced them.
+    pub fn merge(&mut self, other: &Self) {
+        for (&key, &count) in &other.counts {
+            *self.counts.entry(key).or_default() += count;
+        }
+    }
+
     pub fn into_histogram_data(
         self,
         sample_count: u32,
@@ -61,6 +78,129 @@ impl HistogramData {
             .into_option()
             .map(|(min, max)| (*min, *max))
     }
+
+    /// Quantile `q` (clamped to the open interval (0, 1)) over the bins, treating them as a
+    /// discretized distribution: bin keys are sorted ascending, their weights accumulated into a
+    /// CDF, and the bin where the CDF crosses `q` is linearly interpolated between its lower edge
+    /// and the next bin's lower edge (per [`bin_step`]) -- the same bucket-interpolation technique
+    /// Prometheus histograms use for quantile queries. The DNF sentinel bin is excluded, since it
+    /// isn't a finite time. Returns `None` if there's no non-DNF mass to interpolate over, and the
+    /// single present value if only one bin has mass.
+    ///
+    /// Bins with fewer than `min_threshold` of the sample count were already dropped when this
+    /// `HistogramData` was built (see [`HistogramAccumulator::into_histogram_data`]), so extreme
+    /// tail quantiles (very low/high `q`) may fall back to the nearest surviving bin rather than
+    /// the true tail value.
+    pub fn quantile(&self, q: f64, is_fmc: bool, is_average: bool) -> Option<i32> {
+        let q = q.clamp(f64::EPSILON, 1.0 - f64::EPSILON);
+
+        let mut keys: Vec<i32> = self.bins.keys().copied().filter(|&k| k < DNF_VALUE).collect();
+        keys.sort_unstable();
+
+        let total: f64 = keys.iter().map(|k| self.get(k)).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        if keys.len() == 1 {
+            return Some(keys[0]);
+        }
+
+        let target = q * total;
+        let mut cumulative = 0.0;
+
+        for (idx, &key) in keys.iter().enumerate() {
+            let weight = self.get(&key);
+            let next_cumulative = cumulative + weight;
+
+            if next_cumulative >= target || idx == keys.len() - 1 {
+                let step = bin_step(key, is_fmc, is_average);
+                let within = if weight > 0.0 {
+                    ((target - cumulative) / weight).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                return Some(key + (within * step as f64).round() as i32);
+            }
+
+            cumulative = next_cumulative;
+        }
+
+        keys.last().copied()
+    }
+}
+
+/// Online mean/variance/min/max accumulator over a stream of `i32` values, in the classic
+/// `sum`/`sum_sq`/`min`/`max` style -- one pass, constant memory, no need to retain the samples.
+#[derive(Clone)]
+pub struct MomentAccumulator {
+    count: u64,
+    sum: f64,
+    sum_sq: f64,
+    min: i32,
+    max: i32,
+}
+
+impl Default for MomentAccumulator {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            sum: 0.0,
+            sum_sq: 0.0,
+            min: i32::MAX,
+            max: i32::MIN,
+        }
+    }
+}
+
+impl MomentAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, value: i32) {
+        self.count += 1;
+        self.sum += value as f64;
+        self.sum_sq += (value as f64).powi(2);
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    /// Fold another shard's accumulator into this one. Associative and order-independent: the
+    /// underlying `sum`/`sum_sq`/`count` statistics are simply additive, and `min`/`max` compose
+    /// the same way regardless of merge order.
+    pub fn merge(&mut self, other: &Self) {
+        self.count += other.count;
+        self.sum += other.sum;
+        self.sum_sq += other.sum_sq;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    /// `None` if nothing was ever recorded.
+    pub fn into_moment_stats(self) -> Option<MomentStats> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let mean = self.sum / self.count as f64;
+        // Guard against tiny negative variance from floating-point cancellation.
+        let variance = (self.sum_sq / self.count as f64 - mean * mean).max(0.0);
+
+        Some(MomentStats {
+            mean,
+            std_dev: variance.sqrt(),
+            min: self.min,
+            max: self.max,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Serialize)]
+pub struct MomentStats {
+    pub mean: f64,
+    pub std_dev: f64,
+    pub min: i32,
+    pub max: i32,
 }
 
 pub struct RankAccumulator {
@@ -78,6 +218,14 @@ impl RankAccumulator {
         self.counts[rank] += 1;
     }
 
+    /// Fold another shard's rank counts into this one, element-wise. Associative and
+    /// order-independent, for the same reason as [`HistogramAccumulator::merge`].
+    pub fn merge(&mut self, other: &Self) {
+        for (mine, theirs) in self.counts.iter_mut().zip(&other.counts) {
+            *mine += theirs;
+        }
+    }
+
     pub fn into_rank_stats(self, sample_count: u32) -> RankStats {
         let probabilities = self
             .counts
@@ -85,13 +233,20 @@ impl RankAccumulator {
             .map(|c| c as f64 / sample_count as f64)
             .collect();
 
-        RankStats { probabilities }
+        RankStats {
+            probabilities,
+            sample_count,
+        }
     }
 }
 
 #[derive(Clone, Serialize)]
 pub struct RankStats {
     probabilities: Vec<f64>,
+    /// Number of Monte Carlo iterations `probabilities` was estimated from, kept alongside the
+    /// distribution so uncertainty (standard error / confidence interval) can be reported without
+    /// threading the iteration count through every caller separately.
+    sample_count: u32,
 }
 
 impl RankStats {
@@ -99,10 +254,30 @@ impl RankStats {
         self.probabilities.first().copied().unwrap_or(0.0)
     }
 
+    /// Binomial standard error of `win_probability`.
+    pub fn win_chance_se(&self) -> f64 {
+        standard_error(self.win_probability(), self.sample_count)
+    }
+
+    /// 95% Wilson score interval for `win_probability`.
+    pub fn win_chance_ci(&self) -> ConfidenceInterval {
+        wilson_interval(self.win_probability(), self.sample_count)
+    }
+
     pub fn podium_probability(&self) -> f64 {
         self.probabilities.iter().take(3).sum()
     }
 
+    /// Binomial standard error of `podium_probability`.
+    pub fn pod_chance_se(&self) -> f64 {
+        standard_error(self.podium_probability(), self.sample_count)
+    }
+
+    /// 95% Wilson score interval for `podium_probability`.
+    pub fn pod_chance_ci(&self) -> ConfidenceInterval {
+        wilson_interval(self.podium_probability(), self.sample_count)
+    }
+
     pub fn expected_rank(&self) -> f64 {
         self.probabilities
             .iter()
@@ -111,6 +286,40 @@ impl RankStats {
             .sum()
     }
 
+    /// Variance of the per-iteration finishing rank (1-indexed), read directly off the rank
+    /// distribution rather than a running sum of squares -- `probabilities` already *is* the
+    /// full distribution, so `E[rank^2] - E[rank]^2` is exact up to sampling noise.
+    fn rank_variance(&self) -> f64 {
+        let mean = self.expected_rank();
+        let mean_sq: f64 = self
+            .probabilities
+            .iter()
+            .enumerate()
+            .map(|(rank, &prob)| ((rank + 1) as f64).powi(2) * prob)
+            .sum();
+        (mean_sq - mean * mean).max(0.0)
+    }
+
+    /// Standard error of `expected_rank`: the per-iteration rank's sample standard deviation,
+    /// divided by sqrt(N).
+    pub fn expected_rank_se(&self) -> f64 {
+        if self.sample_count == 0 {
+            return 0.0;
+        }
+        (self.rank_variance() / self.sample_count as f64).sqrt()
+    }
+
+    /// 95% interval for `expected_rank`, from the per-iteration rank variance.
+    pub fn expected_rank_ci(&self) -> ConfidenceInterval {
+        let mean = self.expected_rank();
+        let half_width = CONFIDENCE_Z * self.expected_rank_se();
+
+        ConfidenceInterval {
+            lower: (mean - half_width).max(1.0),
+            upper: (mean + half_width).min(self.len() as f64),
+        }
+    }
+
     pub fn as_slice(&self) -> &[f64] {
         &self.probabilities
     }
@@ -0,0 +1,128 @@
+use chrono::{Datelike, Duration, Months, NaiveDate};
+
+use super::models::{ChartData, ChartPoint, RankStats};
+
+/// Choose "nice" axis tick dates for the span `[begin, end]`, the way datetime plotting
+/// coordinates pick key points on a continuous date axis: one tick per first-of-month date
+/// inside the range once the span exceeds ~90 days, weekly ticks once it exceeds ~21 days,
+/// otherwise daily ticks.
+fn select_axis_ticks(begin: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+    let span_days = (end - begin).num_days();
+    if span_days <= 0 {
+        return vec![begin];
+    }
+
+    if span_days > 90 {
+        let mut cursor = begin.with_day(1).expect("day 1 is always valid");
+        if cursor < begin {
+            cursor = cursor
+                .checked_add_months(Months::new(1))
+                .expect("adding a month stays in range");
+        }
+
+        let mut ticks = Vec::new();
+        while cursor <= end {
+            ticks.push(cursor);
+            cursor = cursor
+                .checked_add_months(Months::new(1))
+                .expect("adding a month stays in range");
+        }
+        ticks
+    } else if span_days > 21 {
+        let mut ticks = Vec::new();
+        let mut cursor = begin;
+        while cursor <= end {
+            ticks.push(cursor);
+            cursor += Duration::days(7);
+        }
+        ticks
+    } else {
+        let mut ticks = Vec::new();
+        let mut cursor = begin;
+        while cursor <= end {
+            ticks.push(cursor);
+            cursor += Duration::days(1);
+        }
+        ticks
+    }
+}
+
+/// Fractional position of `date` along the axis `[begin, end]`, matching the key-point
+/// convention used for datetime plotting scales.
+fn fractional_position(date: NaiveDate, begin: NaiveDate, end: NaiveDate) -> f64 {
+    let span = (end - begin).num_days();
+    if span <= 0 {
+        return 0.0;
+    }
+    (date - begin).num_days() as f64 / span as f64
+}
+
+/// Turn a sequence of dated `RankStats` per competitor into a `ChartData` trajectory, with one
+/// point per window. A point whose window end date is one of the "nice" calendar ticks (see
+/// [`select_axis_ticks`]) is labeled with that date; every other point is labeled with its
+/// fractional position along the axis (see [`fractional_position`]), not with a calendar date.
+/// No point is added for a tick date that no window ends on. `metric` picks which scalar is plotted -- e.g.
+/// `RankStats::win_probability` or `RankStats::expected_rank`.
+///
+/// All competitors are expected to share the same window end dates (as produced by running the
+/// same sequence of windows for every competitor at once), so the dates are read off the first
+/// competitor's trajectory.
+fn generate_trajectory_chart(
+    competitors: &[(&str, &[(NaiveDate, RankStats)])],
+    metric: impl Fn(&RankStats) -> f64,
+) -> ChartData {
+    let labels = competitors.iter().map(|(name, _)| name.to_string()).collect();
+
+    let Some(window_dates) = competitors.first().map(|(_, points)| *points) else {
+        return ChartData {
+            labels,
+            data: vec![],
+        };
+    };
+    if window_dates.is_empty() {
+        return ChartData {
+            labels,
+            data: vec![],
+        };
+    }
+
+    let begin = window_dates[0].0;
+    let end = window_dates[window_dates.len() - 1].0;
+    let ticks = select_axis_ticks(begin, end);
+
+    let data = window_dates
+        .iter()
+        .enumerate()
+        .map(|(idx, &(date, _))| {
+            let position = fractional_position(date, begin, end);
+            let name = if ticks.contains(&date) {
+                date.to_string()
+            } else {
+                format!("{:.4}", position)
+            };
+
+            let values = competitors
+                .iter()
+                .map(|(_, points)| points.get(idx).map(|(_, stats)| metric(stats)).unwrap_or(0.0))
+                .collect();
+
+            ChartPoint { name, values }
+        })
+        .collect();
+
+    ChartData { labels, data }
+}
+
+/// Win-probability trajectory across rolling history windows.
+pub fn generate_win_chance_trajectory_chart(
+    competitors: &[(&str, &[(NaiveDate, RankStats)])],
+) -> ChartData {
+    generate_trajectory_chart(competitors, RankStats::win_probability)
+}
+
+/// Expected-rank trajectory across rolling history windows.
+pub fn generate_expected_rank_trajectory_chart(
+    competitors: &[(&str, &[(NaiveDate, RankStats)])],
+) -> ChartData {
+    generate_trajectory_chart(competitors, RankStats::expected_rank)
+}
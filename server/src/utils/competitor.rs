@@ -1,5 +1,6 @@
 use super::calc;
-use super::types::{CompetitorStats, DatedCompetitionResult};
+use super::metrics;
+use super::types::{CompetitorStats, DatedCompetitionResult, EventType, MultiBlindStats};
 
 #[derive(Debug)]
 pub struct Competitor {
@@ -7,6 +8,7 @@ pub struct Competitor {
     pub id: String,
     pub entered_results: Vec<i32>,
     pub stats: Option<CompetitorStats>,
+    pub multi_blind_stats: Option<MultiBlindStats>,
 }
 
 impl Competitor {
@@ -15,20 +17,45 @@ impl Competitor {
         id: String,
         results: Vec<DatedCompetitionResult>,
         halflife: f32,
+        event_type: EventType,
     ) -> Self {
-        let stats = Self::calculate_stats(&results, halflife);
+        let (stats, multi_blind_stats) = Self::calculate_stats(&results, halflife, event_type);
         Self {
             name,
             id,
             entered_results: vec![],
             stats,
+            multi_blind_stats,
         }
     }
 
+    /// Fit this competitor's stats for `event_type`. Multi-Blind results aren't a single
+    /// magnitude (they're a points/time pair), so they get their own distribution instead of
+    /// the skew-normal time fit used by every other event.
     pub fn calculate_stats(
         results: &[DatedCompetitionResult],
         halflife: f32,
+        event_type: EventType,
+    ) -> (Option<CompetitorStats>, Option<MultiBlindStats>) {
+        if matches!(event_type, EventType::MultiBlind) {
+            return (None, Self::calculate_multi_blind_stats(results, halflife));
+        }
+
+        (Self::calculate_time_stats(results, halflife), None)
+    }
+
+    fn calculate_time_stats(
+        results: &[DatedCompetitionResult],
+        halflife: f32,
     ) -> Option<CompetitorStats> {
+        let total_solves: u64 = results.iter().map(|r| r.results.len() as u64).sum();
+        let dnf_solves: u64 = results
+            .iter()
+            .flat_map(|r| &r.results)
+            .filter(|&&value| value < 0)
+            .count() as u64;
+        metrics::record_dnf_ratio(dnf_solves, total_solves);
+
         let weighted = Self::apply_weights(results, halflife);
         if weighted.is_empty() {
             return None;
@@ -58,7 +85,8 @@ impl Competitor {
         let num_non_dnf_results = valid_times.len() as u32;
         let (mean, _, stdev) = calc::calc_weighted_stats(&valid_times);
         let trimmed = calc::trim_outliers(valid_times, mean, stdev);
-        let (skew, shape, location) = calc::fit_weighted_skewnorm(&trimmed);
+        let (skew, shape, location) =
+            metrics::time_phase("skewnorm_fit", || calc::fit_weighted_skewnorm(&trimmed));
 
         Some(CompetitorStats {
             location,
@@ -71,6 +99,52 @@ impl Competitor {
         })
     }
 
+    fn calculate_multi_blind_stats(
+        results: &[DatedCompetitionResult],
+        halflife: f32,
+    ) -> Option<MultiBlindStats> {
+        let weighted = Self::apply_weights(results, halflife);
+        let total_w: f32 = weighted.iter().map(|(_, w)| *w).sum();
+        if weighted.is_empty() || total_w <= 0.0 {
+            return None;
+        }
+
+        let decoded: Vec<(i32, i32, f32)> = weighted
+            .iter()
+            .filter_map(|&(raw, w)| {
+                decode_multi_blind_value(raw).map(|(points, time_seconds)| (points, time_seconds, w))
+            })
+            .collect();
+
+        let solved_weight: f32 = decoded.iter().map(|&(_, _, w)| w).sum();
+        let dnf_rate = ((total_w - solved_weight) / total_w).clamp(0.0, 1.0);
+
+        if decoded.is_empty() {
+            return Some(MultiBlindStats {
+                mean_points: 0.0,
+                points_stdev: 0.0,
+                mean_time: 0.0,
+                time_stdev: 0.0,
+                dnf_rate,
+            });
+        }
+
+        let points_weighted: Vec<(i32, f32)> =
+            decoded.iter().map(|&(points, _, w)| (points, w)).collect();
+        let time_weighted: Vec<(i32, f32)> = decoded.iter().map(|&(_, t, w)| (t, w)).collect();
+
+        let (mean_points, _, points_stdev) = calc::calc_weighted_stats(&points_weighted);
+        let (mean_time, _, time_stdev) = calc::calc_weighted_stats(&time_weighted);
+
+        Some(MultiBlindStats {
+            mean_points,
+            points_stdev,
+            mean_time,
+            time_stdev,
+            dnf_rate,
+        })
+    }
+
     fn apply_weights(results: &[DatedCompetitionResult], halflife: f32) -> Vec<(i32, f32)> {
         let decay_rate = std::f32::consts::LN_2 / halflife;
         let mut weighted = Vec::new();
@@ -95,3 +169,21 @@ impl Competitor {
     //     }
     // }
 }
+
+/// Decode a raw `333mbf` result value into `(points, time_seconds)`, per the WCA encoding
+/// `value = (99 - points) * 1e7 + seconds * 100 + missed`. Returns `None` for a DNF/DNS
+/// (non-positive raw value, or decoded points <= 0).
+pub(crate) fn decode_multi_blind_value(raw: i32) -> Option<(i32, i32)> {
+    if raw <= 0 {
+        return None;
+    }
+
+    let points = 99 - raw / 10_000_000;
+    let time_seconds = (raw % 10_000_000) / 100;
+
+    if points <= 0 {
+        None
+    } else {
+        Some((points, time_seconds))
+    }
+}
@@ -1,6 +1,7 @@
 use super::model::Competitor;
 use crate::utils::database;
 use crate::utils::http::AppError;
+use crate::utils::metrics;
 use crate::utils::wca::{EventType, clean_and_validate_wca_id};
 use chrono::{Months, NaiveDate};
 use sqlx::PgPool;
@@ -19,6 +20,28 @@ impl CompetitorContext {
         start_date: NaiveDate,
         end_date: NaiveDate,
         half_life: f32,
+    ) -> Result<Self, AppError> {
+        metrics::time_phase_async(
+            "competitor_context_load",
+            Self::load_inner(
+                pool,
+                competitor_ids,
+                event_id,
+                start_date,
+                end_date,
+                half_life,
+            ),
+        )
+        .await
+    }
+
+    async fn load_inner(
+        pool: &PgPool,
+        competitor_ids: &[String],
+        event_id: &str,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        half_life: f32,
     ) -> Result<Self, AppError> {
         let event_type = EventType::from_id(event_id)
             .ok_or_else(|| AppError::BadRequest(format!("Invalid event: {}", event_id)))?;
@@ -43,7 +66,9 @@ impl CompetitorContext {
                 let results = dated_results_map.remove(&id).unwrap_or_default();
                 Competitor::new(name, id, results, half_life)
             })
-            .collect();
+            .collect::<Vec<Competitor>>();
+
+        metrics::record_competitor_count(competitors.len() as u64);
 
         Ok(Self {
             competitors,
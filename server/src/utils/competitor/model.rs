@@ -1,4 +1,5 @@
 use super::statistics::{self, SkewNormParams, WeightedStats};
+use crate::utils::metrics;
 
 #[derive(Debug, Clone)]
 pub struct DatedCompetitionResult {
@@ -50,6 +51,14 @@ impl Competitor {
         results: &[DatedCompetitionResult],
         halflife: f32,
     ) -> Option<CompetitorStats> {
+        let total_solves: u64 = results.iter().map(|r| r.results.len() as u64).sum();
+        let dnf_solves: u64 = results
+            .iter()
+            .flat_map(|r| &r.results)
+            .filter(|&&value| value < 0)
+            .count() as u64;
+        metrics::record_dnf_ratio(dnf_solves, total_solves);
+
         let weighted = Self::apply_weights(results, halflife);
         if weighted.is_empty() {
             return None;
@@ -78,7 +87,8 @@ impl Competitor {
         let num_non_dnf_results = valid_times.len() as u32;
         let stats: WeightedStats = statistics::calc_weighted_stats(&valid_times);
         let trimmed = statistics::trim_outliers(valid_times, &stats);
-        let params: SkewNormParams = statistics::fit_weighted_skewnorm(&trimmed);
+        let params: SkewNormParams =
+            metrics::time_phase("skewnorm_fit", || statistics::fit_weighted_skewnorm(&trimmed));
 
         Some(CompetitorStats {
             location: params.xi,
@@ -106,6 +106,38 @@ pub fn fit_weighted_skewnorm(data: &[(i32, f32)]) -> SkewNormParams {
     SkewNormParams { alpha, omega, xi }
 }
 
+/// Standard normal PDF.
+fn std_normal_pdf(x: f32) -> f32 {
+    (-0.5 * x * x).exp() / (2.0 * PI).sqrt()
+}
+
+/// Standard normal CDF, via the Abramowitz-Stegun 7.1.26 approximation (max error ~1.5e-7).
+fn std_normal_cdf(x: f32) -> f32 {
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let x = x.abs();
+
+    let a1 = 0.254829592;
+    let a2 = -0.284496736;
+    let a3 = 1.421413741;
+    let a4 = -1.453152027;
+    let a5 = 1.061405429;
+    let p = 0.3275911;
+
+    let t = 1.0 / (1.0 + p * x);
+    let poly = ((((a5 * t + a4) * t + a3) * t + a2) * t + a1) * t;
+    let erf = 1.0 - poly * (-x * x).exp();
+
+    0.5 * (1.0 + sign * erf)
+}
+
+/// Skew-normal log-density `ln(2/omega * phi((x-xi)/omega) * Phi(alpha*(x-xi)/omega))`, used to
+/// score how well a fitted distribution predicts a held-out solve.
+pub fn skewnorm_log_pdf(x: i32, params: SkewNormParams) -> f32 {
+    let z = (x as f32 - params.xi) / params.omega;
+    let pdf = (2.0 / params.omega) * std_normal_pdf(z) * std_normal_cdf(params.alpha * z);
+    pdf.max(f32::MIN_POSITIVE).ln()
+}
+
 /// Remove outliers beyond 2 standard deviations from the mean.
 pub fn trim_outliers(data: Vec<(i32, f32)>, stats: &WeightedStats) -> Vec<(i32, f32)> {
     let threshold = (stats.mean + stats.stdev * 2.0) as i32;
@@ -0,0 +1,16 @@
+// --- WCA CONSTANTS ---
+
+/// DNF (Did Not Finish) sentinel value, greater than 1 hour in centiseconds.
+pub const DNF_VALUE: i32 = 60 * 60 * 100 + 1;
+
+/// Number of solves for Average of 5 format.
+pub const AO5_SOLVE_COUNT: usize = 5;
+
+/// Number of solves for Best of 5 format.
+pub const BO5_SOLVE_COUNT: usize = 5;
+
+/// Number of solves for Mean of 3 format.
+pub const MO3_SOLVE_COUNT: usize = 3;
+
+/// Number of solves for Best of 3 format.
+pub const BO3_SOLVE_COUNT: usize = 3;
@@ -1,4 +1,5 @@
 use crate::utils::competitor::DatedCompetitionResult;
+use crate::utils::metrics;
 use chrono::{NaiveDate, Utc};
 use sqlx::{FromRow, PgPool};
 use std::collections::HashMap;
@@ -18,25 +19,28 @@ pub async fn fetch_competitor_results<T: AsRef<str>>(
     start_date: NaiveDate,
     end_date: NaiveDate,
 ) -> Result<Vec<CompetitorRow>, sqlx::Error> {
-    sqlx::query_as::<_, CompetitorRow>(
-        r#"
-        SELECT person_id, competition_date, value 
-        FROM results 
-        WHERE person_id = ANY($1) 
+    metrics::time_phase_async("fetch_competitor_results", async {
+        sqlx::query_as::<_, CompetitorRow>(
+            r#"
+        SELECT person_id, competition_date, value
+        FROM results
+        WHERE person_id = ANY($1)
         AND event_id = $2
         AND competition_date BETWEEN $3 AND $4
         "#,
-    )
-    .bind(
-        competitor_ids
-            .iter()
-            .map(|s| s.as_ref())
-            .collect::<Vec<_>>(),
-    )
-    .bind(event_id)
-    .bind(start_date)
-    .bind(end_date)
-    .fetch_all(pool)
+        )
+        .bind(
+            competitor_ids
+                .iter()
+                .map(|s| s.as_ref())
+                .collect::<Vec<_>>(),
+        )
+        .bind(event_id)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(pool)
+        .await
+    })
     .await
 }
 
@@ -44,16 +48,19 @@ pub async fn fetch_competitor_names<T: AsRef<str>>(
     pool: &PgPool,
     competitor_ids: &[T],
 ) -> Result<Vec<(String, String)>, sqlx::Error> {
-    sqlx::query_as::<_, (String, String)>(
-        r#"SELECT person_id, name from persons WHERE person_id = ANY($1)"#,
-    )
-    .bind(
-        competitor_ids
-            .iter()
-            .map(|s| s.as_ref())
-            .collect::<Vec<_>>(),
-    )
-    .fetch_all(pool)
+    metrics::time_phase_async("fetch_competitor_names", async {
+        sqlx::query_as::<_, (String, String)>(
+            r#"SELECT person_id, name from persons WHERE person_id = ANY($1)"#,
+        )
+        .bind(
+            competitor_ids
+                .iter()
+                .map(|s| s.as_ref())
+                .collect::<Vec<_>>(),
+        )
+        .fetch_all(pool)
+        .await
+    })
     .await
 }
 
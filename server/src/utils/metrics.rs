@@ -0,0 +1,265 @@
+//! Lightweight in-process metrics: per-phase latency histograms plus simple counters, exposed via
+//! [`render_prometheus_text`] for a `/metrics` handler to serve directly to a Prometheus scraper.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::future::Future;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+// Latency bucket upper bounds (ms): 1, 2, 4, ... doubling up to ~65.5s, plus an implicit `+Inf`
+// bucket added at render time, per the Prometheus histogram convention.
+const LATENCY_BUCKET_COUNT: u32 = 17;
+
+fn latency_bucket_bounds_ms() -> Vec<f64> {
+    (0..LATENCY_BUCKET_COUNT).map(|i| 2f64.powi(i as i32)).collect()
+}
+
+struct Histogram {
+    bucket_bounds: Vec<f64>,
+    bucket_counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn new(bucket_bounds: Vec<f64>) -> Self {
+        let bucket_counts = vec![0; bucket_bounds.len()];
+        Self {
+            bucket_bounds,
+            bucket_counts,
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    /// Increment every bucket whose `le` upper bound `value` falls under, plus the running
+    /// sum/count -- exactly how Prometheus accumulates an observation into cumulative buckets.
+    fn observe(&mut self, value: f64) {
+        for (bound, count) in self.bucket_bounds.iter().zip(&mut self.bucket_counts) {
+            if value <= *bound {
+                *count += 1;
+            }
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+}
+
+#[derive(Default)]
+struct Registry {
+    latency: HashMap<&'static str, Histogram>,
+    counters: HashMap<&'static str, f64>,
+}
+
+fn registry() -> &'static Mutex<Registry> {
+    static REGISTRY: OnceLock<Mutex<Registry>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(Registry::default()))
+}
+
+/// Record one latency observation (in milliseconds) for `phase`, e.g. `"fetch_competitor_results"`.
+pub fn record_latency(phase: &'static str, duration: Duration) {
+    let mut reg = registry().lock().expect("metrics registry mutex poisoned");
+    reg.latency
+        .entry(phase)
+        .or_insert_with(|| Histogram::new(latency_bucket_bounds_ms()))
+        .observe(duration.as_secs_f64() * 1000.0);
+}
+
+/// Time a synchronous phase and record its latency under `phase`.
+pub fn time_phase<T>(phase: &'static str, f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let result = f();
+    record_latency(phase, start.elapsed());
+    result
+}
+
+/// Time an async phase and record its latency under `phase`.
+pub async fn time_phase_async<T>(phase: &'static str, fut: impl Future<Output = T>) -> T {
+    let start = Instant::now();
+    let result = fut.await;
+    record_latency(phase, start.elapsed());
+    result
+}
+
+fn incr_counter(name: &'static str, by: f64) {
+    let mut reg = registry().lock().expect("metrics registry mutex poisoned");
+    *reg.counters.entry(name).or_insert(0.0) += by;
+}
+
+/// Count one handled HTTP request (any route).
+pub fn record_request() {
+    incr_counter("wca_odds_requests_total", 1.0);
+}
+
+/// Add `n` to the running total of competitors seen across all requests.
+pub fn record_competitor_count(n: u64) {
+    incr_counter("wca_odds_competitors_total", n as f64);
+}
+
+/// Add `dnf_count` DNFs out of `total_count` solves to the running totals -- exposed as two
+/// counters so a scraper can derive the DNF ratio itself via `rate(dnf) / rate(total)`.
+pub fn record_dnf_ratio(dnf_count: u64, total_count: u64) {
+    incr_counter("wca_odds_dnf_results_total", dnf_count as f64);
+    incr_counter("wca_odds_solve_results_total", total_count as f64);
+}
+
+/// Render every registered metric in the Prometheus text exposition format.
+pub fn render_prometheus_text() -> String {
+    let reg = registry().lock().expect("metrics registry mutex poisoned");
+    let mut out = String::new();
+
+    let mut phases: Vec<&'static str> = reg.latency.keys().copied().collect();
+    phases.sort_unstable();
+    for phase in phases {
+        let hist = &reg.latency[phase];
+        let metric = format!("wca_odds_phase_latency_ms_{phase}");
+        let _ = writeln!(out, "# HELP {metric} Latency (ms) of the \"{phase}\" phase.");
+        let _ = writeln!(out, "# TYPE {metric} histogram");
+        for (bound, count) in hist.bucket_bounds.iter().zip(&hist.bucket_counts) {
+            let _ = writeln!(out, "{metric}_bucket{{le=\"{bound}\"}} {count}");
+        }
+        let _ = writeln!(out, "{metric}_bucket{{le=\"+Inf\"}} {}", hist.count);
+        let _ = writeln!(out, "{metric}_sum {}", hist.sum);
+        let _ = writeln!(out, "{metric}_count {}", hist.count);
+    }
+
+    let mut counters: Vec<&'static str> = reg.counters.keys().copied().collect();
+    counters.sort_unstable();
+    for name in counters {
+        let _ = writeln!(out, "# HELP {name} {}", counter_help(name));
+        let _ = writeln!(out, "# TYPE {name} counter");
+        let _ = writeln!(out, "{name} {}", reg.counters[name]);
+    }
+
+    out
+}
+
+/// Human-readable description for a global counter, for the `# HELP` line above its `# TYPE`.
+/// Falls back to the metric name itself for any counter not in this list (there shouldn't be
+/// any, but `render_prometheus_text` iterates the registry rather than a fixed set of names).
+fn counter_help(name: &str) -> &str {
+    match name {
+        "wca_odds_requests_total" => "Total HTTP requests handled, across all routes.",
+        "wca_odds_competitors_total" => "Total competitors seen across all requests.",
+        "wca_odds_dnf_results_total" => "Total DNF solves seen across all requests.",
+        "wca_odds_solve_results_total" => "Total solves (DNF or not) seen across all requests.",
+        _ => name,
+    }
+}
+
+// --- HTTP-facing, axum-state-injected registry (distinct from the global per-phase registry
+// above): total requests per route/status, plus fixed-bucket latency histograms for overall
+// request handling and simulation compute time. Injected into `AppState` the same way the PG
+// pool is, rather than living behind the global `OnceLock`, since it's scoped to one HTTP layer
+// (`timer_middleware`) and one handler (`simulation_handler`) rather than every call site.
+//
+// This isn't redundant with the global registry's `wca_odds_requests_total`: that counter stays
+// unlabeled (no method/path/status) to match the other global domain counters above
+// (competitors/DNFs/solves seen), while this one exists specifically to carry the per-route,
+// per-status breakdown and latency histograms those domain counters don't. Both are updated from
+// the single instrumentation point in `timer_middleware` (see `record_request` there), so a
+// request is still only counted once per registry, not duplicated across handlers.
+
+/// Fixed cumulative bucket upper bounds (ms) for HTTP-facing latency histograms, chosen to cover
+/// typical request/compute latencies at Prometheus-friendly round numbers.
+const HTTP_LATENCY_BUCKET_BOUNDS_MS: [f64; 10] =
+    [5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0, 2500.0, 5000.0];
+
+#[derive(Default)]
+struct HttpMetricsState {
+    requests_total: HashMap<(String, String, u16), u64>,
+    request_latency: Option<Histogram>,
+    simulation_compute: Option<Histogram>,
+}
+
+/// Shared request/latency metrics registry, injected as axum state alongside the PG pool.
+#[derive(Clone)]
+pub struct HttpMetricsRegistry(Arc<Mutex<HttpMetricsState>>);
+
+impl HttpMetricsRegistry {
+    pub fn new() -> Self {
+        Self(Arc::new(Mutex::new(HttpMetricsState::default())))
+    }
+
+    /// Record one completed HTTP request: bumps the `(method, path, status)` counter and observes
+    /// `latency` into the shared request-latency histogram.
+    pub fn record_request(&self, method: &str, path: &str, status: u16, latency: Duration) {
+        let mut state = self.0.lock().expect("http metrics registry mutex poisoned");
+        *state
+            .requests_total
+            .entry((method.to_string(), path.to_string(), status))
+            .or_insert(0) += 1;
+        state
+            .request_latency
+            .get_or_insert_with(|| Histogram::new(HTTP_LATENCY_BUCKET_BOUNDS_MS.to_vec()))
+            .observe(latency.as_secs_f64() * 1000.0);
+    }
+
+    /// Observe one simulation's Monte Carlo compute time, separate from the surrounding
+    /// request/response latency recorded by `record_request`.
+    pub fn record_simulation_compute(&self, duration: Duration) {
+        let mut state = self.0.lock().expect("http metrics registry mutex poisoned");
+        state
+            .simulation_compute
+            .get_or_insert_with(|| Histogram::new(HTTP_LATENCY_BUCKET_BOUNDS_MS.to_vec()))
+            .observe(duration.as_secs_f64() * 1000.0);
+    }
+
+    /// Render the request/status counters and latency histograms in Prometheus text exposition
+    /// format.
+    pub fn render_prometheus_text(&self) -> String {
+        let state = self.0.lock().expect("http metrics registry mutex poisoned");
+        let mut out = String::new();
+
+        let _ = writeln!(
+            out,
+            "# HELP wca_odds_http_requests_total Total HTTP requests handled, by method/path/status."
+        );
+        let _ = writeln!(out, "# TYPE wca_odds_http_requests_total counter");
+        let mut keys: Vec<&(String, String, u16)> = state.requests_total.keys().collect();
+        keys.sort_unstable();
+        for key @ (method, path, status) in keys {
+            let count = state.requests_total[key];
+            let _ = writeln!(
+                out,
+                "wca_odds_http_requests_total{{method=\"{method}\",path=\"{path}\",status=\"{status}\"}} {count}"
+            );
+        }
+
+        write_histogram(
+            &mut out,
+            "wca_odds_http_request_duration_ms",
+            "End-to-end HTTP request duration (ms), across all routes.",
+            state.request_latency.as_ref(),
+        );
+        write_histogram(
+            &mut out,
+            "wca_odds_simulation_compute_duration_ms",
+            "Monte Carlo simulation compute time (ms), excluding request/response overhead.",
+            state.simulation_compute.as_ref(),
+        );
+
+        out
+    }
+}
+
+impl Default for HttpMetricsRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn write_histogram(out: &mut String, metric: &str, help: &str, hist: Option<&Histogram>) {
+    let Some(hist) = hist else {
+        return;
+    };
+    let _ = writeln!(out, "# HELP {metric} {help}");
+    let _ = writeln!(out, "# TYPE {metric} histogram");
+    for (bound, count) in hist.bucket_bounds.iter().zip(&hist.bucket_counts) {
+        let _ = writeln!(out, "{metric}_bucket{{le=\"{bound}\"}} {count}");
+    }
+    let _ = writeln!(out, "{metric}_bucket{{le=\"+Inf\"}} {}", hist.count);
+    let _ = writeln!(out, "{metric}_sum {}", hist.sum);
+    let _ = writeln!(out, "{metric}_count {}", hist.count);
+}
@@ -13,6 +13,9 @@ use std::{
 };
 use tower_governor::{GovernorError, key_extractor::KeyExtractor};
 
+use crate::utils::metrics;
+use crate::utils::state::AppState;
+
 #[derive(Clone, Copy)]
 pub struct ForwardedIpExtractor;
 
@@ -35,7 +38,11 @@ impl KeyExtractor for ForwardedIpExtractor {
     }
 }
 
-pub async fn timer_middleware(req: Request<Body>, next: Next) -> Response {
+pub async fn timer_middleware(
+    State(state): State<AppState>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
     let start = Instant::now();
     let path = req.uri().path().to_string();
     let method = req.method().clone();
@@ -51,6 +58,19 @@ pub async fn timer_middleware(req: Request<Body>, next: Next) -> Response {
         latency
     );
 
+    // The single instrumentation point for "a request happened": the unlabeled global counter
+    // (alongside the other domain counters in `metrics`) and the labeled/latency-tracking HTTP
+    // registry are both updated from here, rather than each handler calling `record_request`
+    // itself, so every route is counted exactly once regardless of whether its handler
+    // remembers to.
+    metrics::record_request();
+    state.http_metrics.record_request(
+        method.as_str(),
+        &path,
+        response.status().as_u16(),
+        latency,
+    );
+
     response
 }
 
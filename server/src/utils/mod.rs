@@ -1,8 +1,17 @@
+pub mod analytic;
+pub mod cache;
 pub mod calc;
+pub mod calibration;
+pub mod charts;
 pub mod competitor;
 pub mod constants;
 pub mod database;
+pub mod metrics;
+pub mod quantile;
+pub mod rating;
 pub mod simulation;
+pub mod state;
+pub mod stats;
 pub mod types;
 pub mod validation;
 pub mod key_extractor;
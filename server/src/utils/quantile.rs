@@ -0,0 +1,239 @@
+/// Target rank-bound width, expressed as a fraction of the sample count: a query for the `phi`
+/// quantile is guaranteed to return a value whose true rank is within `QUANTILE_EPSILON * N` of
+/// `phi * N`.
+const QUANTILE_EPSILON: f64 = 0.01;
+
+#[derive(Clone, Debug)]
+struct Entry {
+    value: i32,
+    rmin: u32,
+    rmax: u32,
+}
+
+/// Streaming approximate-quantile summary (Greenwald-Khanna style), maintained as a value-sorted
+/// list of `(value, rmin, rmax)` tuples bounding each inserted value's true rank. Tuples are
+/// periodically dropped whenever the rank gap left between their neighbours is within tolerance,
+/// keeping memory at O((1/epsilon) * log(epsilon*N)) for N inserted samples rather than growing
+/// with the number of distinct values like the raw per-value histogram does.
+#[derive(Clone, Debug)]
+pub struct QuantileSummary {
+    epsilon: f64,
+    entries: Vec<Entry>,
+    count: u32,
+    since_compress: u32,
+    compress_interval: u32,
+}
+
+impl QuantileSummary {
+    pub fn new() -> Self {
+        Self::with_epsilon(QUANTILE_EPSILON)
+    }
+
+    pub fn with_epsilon(epsilon: f64) -> Self {
+        Self {
+            epsilon,
+            entries: Vec::new(),
+            count: 0,
+            since_compress: 0,
+            // Compressing roughly every 1/(2*epsilon) insertions keeps the summary close to its
+            // asymptotic size without paying the O(entries) compression cost on every insert.
+            compress_interval: (1.0 / (2.0 * epsilon)).ceil().max(1.0) as u32,
+        }
+    }
+
+    pub fn insert(&mut self, value: i32) {
+        let idx = self.entries.partition_point(|e| e.value < value);
+
+        let rmin = if idx == 0 {
+            1
+        } else {
+            self.entries[idx - 1].rmin + 1
+        };
+        let rmax = if idx == self.entries.len() {
+            self.count + 1
+        } else {
+            self.entries[idx].rmax + 1
+        };
+
+        for later in &mut self.entries[idx..] {
+            later.rmin += 1;
+            later.rmax += 1;
+        }
+        self.entries.insert(idx, Entry { value, rmin, rmax });
+        self.count += 1;
+        self.since_compress += 1;
+
+        if self.since_compress >= self.compress_interval {
+            self.compress();
+            self.since_compress = 0;
+        }
+    }
+
+    /// Drop a tuple whenever its two neighbours would still span a rank gap of at most
+    /// `2 * epsilon * N`, per the Greenwald-Khanna compression invariant. The first and last
+    /// tuples are never dropped, since they anchor the summary's min/max.
+    fn compress(&mut self) {
+        if self.entries.len() < 3 {
+            return;
+        }
+
+        let threshold = (2.0 * self.epsilon * self.count as f64).floor() as u32;
+        let last_idx = self.entries.len() - 1;
+
+        let mut compressed = Vec::with_capacity(self.entries.len());
+        compressed.push(self.entries[0].clone());
+
+        for (i, entry) in self.entries[1..last_idx].iter().enumerate() {
+            let prev = compressed.last().expect("seeded with the first entry above");
+            // Dropping `entry` leaves `prev` and the entry after it adjacent, so the rank gap
+            // between them is what has to stay within the tolerance.
+            let band = self.entries[i + 2].rmax.saturating_sub(prev.rmin);
+
+            if band > threshold {
+                compressed.push(entry.clone());
+            }
+        }
+
+        compressed.push(self.entries[last_idx].clone());
+        self.entries = compressed;
+    }
+
+    /// Approximate value at quantile `phi` (0.0..=1.0), guaranteed to have true rank within
+    /// `epsilon * N` of `phi * N`. Returns `None` if nothing has been inserted yet.
+    pub fn query(&self, phi: f64) -> Option<i32> {
+        if self.entries.is_empty() {
+            return None;
+        }
+
+        let target_rank = (phi.clamp(0.0, 1.0) * self.count as f64).round().max(1.0) as u32;
+
+        self.entries
+            .iter()
+            .min_by_key(|e| e.rmin.abs_diff(target_rank).max(e.rmax.abs_diff(target_rank)))
+            .map(|e| e.value)
+    }
+
+    /// Merge `other`'s samples into this summary, so two summaries built independently (e.g. by
+    /// separate simulation shards) can be combined into one over their full combined stream.
+    ///
+    /// Per Greenwald & Khanna's treatment of combining rank-bound summaries, a value's rank
+    /// bounds in the merged stream are the sum of its rank bounds in each input stream -- so for
+    /// every tuple from one summary, its bounds within the *other* summary's stream are
+    /// approximated from that summary's bracketing entries, and added on. The result is
+    /// compressed once to bring it back toward the usual asymptotic size.
+    pub fn merge(&mut self, other: &QuantileSummary) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = other.clone();
+            return;
+        }
+
+        let rank_bounds_in = |entries: &[Entry], count: u32, value: i32| -> (u32, u32) {
+            if entries.is_empty() {
+                return (0, 0);
+            }
+            let idx = entries.partition_point(|e| e.value < value);
+            if idx == 0 {
+                (0, entries[0].rmax.min(count))
+            } else if idx == entries.len() {
+                (entries[idx - 1].rmin, count)
+            } else {
+                (entries[idx - 1].rmin, entries[idx].rmax)
+            }
+        };
+
+        let mut merged = Vec::with_capacity(self.entries.len() + other.entries.len());
+        for e in &self.entries {
+            let (other_rmin, other_rmax) = rank_bounds_in(&other.entries, other.count, e.value);
+            merged.push(Entry {
+                value: e.value,
+                rmin: e.rmin + other_rmin,
+                rmax: e.rmax + other_rmax,
+            });
+        }
+        for e in &other.entries {
+            let (self_rmin, self_rmax) = rank_bounds_in(&self.entries, self.count, e.value);
+            merged.push(Entry {
+                value: e.value,
+                rmin: e.rmin + self_rmin,
+                rmax: e.rmax + self_rmax,
+            });
+        }
+        merged.sort_by_key(|e| e.value);
+
+        self.count += other.count;
+        self.compress_interval = (1.0 / (2.0 * self.epsilon)).ceil().max(1.0) as u32;
+        self.entries = merged;
+        self.since_compress = 0;
+        self.compress();
+    }
+}
+
+impl Default for QuantileSummary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_returns_none_before_any_insert() {
+        let summary = QuantileSummary::new();
+        assert_eq!(summary.query(0.5), None);
+    }
+
+    #[test]
+    fn query_approximates_median_of_a_uniform_stream() {
+        let mut summary = QuantileSummary::new();
+        for v in 1..=1000 {
+            summary.insert(v);
+        }
+
+        let median = summary.query(0.5).unwrap();
+        let tolerance = (QUANTILE_EPSILON * 1000.0).ceil() as i32;
+        assert!((median - 500).abs() <= tolerance);
+    }
+
+    #[test]
+    fn query_bounds_hold_at_the_extremes() {
+        let mut summary = QuantileSummary::new();
+        for v in 1..=200 {
+            summary.insert(v);
+        }
+
+        assert_eq!(summary.query(0.0), Some(1));
+        assert_eq!(summary.query(1.0), Some(200));
+    }
+
+    #[test]
+    fn merge_combines_two_independently_built_summaries() {
+        let mut a = QuantileSummary::new();
+        let mut b = QuantileSummary::new();
+        for v in 1..=500 {
+            a.insert(v);
+        }
+        for v in 501..=1000 {
+            b.insert(v);
+        }
+
+        a.merge(&b);
+        let median = a.query(0.5).unwrap();
+        let tolerance = (QUANTILE_EPSILON * 1000.0).ceil() as i32;
+        assert!((median - 500).abs() <= tolerance);
+    }
+
+    #[test]
+    fn merge_into_empty_summary_adopts_the_other() {
+        let mut empty = QuantileSummary::new();
+        let mut other = QuantileSummary::new();
+        other.insert(42);
+
+        empty.merge(&other);
+        assert_eq!(empty.query(0.5), Some(42));
+    }
+}
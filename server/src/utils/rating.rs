@@ -0,0 +1,216 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap};
+use std::f64::consts::PI;
+
+use chrono::NaiveDate;
+
+/// Public-scale Glicko-2 defaults, per Glickman's "Example of the Glicko-2 system".
+const DEFAULT_RATING: f64 = 1500.0;
+const DEFAULT_RD: f64 = 350.0;
+const DEFAULT_VOLATILITY: f64 = 0.06;
+
+/// Converts between the public rating scale and the internal Glicko-2 scale.
+const GLICKO2_SCALE: f64 = 173.7178;
+
+/// System volatility constraint; smaller values restrict rating swings between periods.
+const TAU: f64 = 0.5;
+
+/// Convergence tolerance for the Illinois algorithm volatility solve.
+const VOLATILITY_EPSILON: f64 = 1e-6;
+
+/// A competitor's Glicko-2 skill estimate: rating, rating deviation, and volatility.
+#[derive(Debug, Clone, Copy)]
+pub struct Glicko2Rating {
+    pub rating: f64,
+    pub rd: f64,
+    pub volatility: f64,
+}
+
+impl Default for Glicko2Rating {
+    fn default() -> Self {
+        Self {
+            rating: DEFAULT_RATING,
+            rd: DEFAULT_RD,
+            volatility: DEFAULT_VOLATILITY,
+        }
+    }
+}
+
+impl Glicko2Rating {
+    fn mu(&self) -> f64 {
+        (self.rating - DEFAULT_RATING) / GLICKO2_SCALE
+    }
+
+    fn phi(&self) -> f64 {
+        self.rd / GLICKO2_SCALE
+    }
+
+    fn from_internal(mu: f64, phi: f64, volatility: f64) -> Self {
+        Self {
+            rating: mu * GLICKO2_SCALE + DEFAULT_RATING,
+            rd: phi * GLICKO2_SCALE,
+            volatility,
+        }
+    }
+
+    /// Apply one rating period's game results against the given opponents (each `(opponent,
+    /// score)` with `score` in `{0.0, 0.5, 1.0}`). An empty slice is treated as an inactive
+    /// period: only the rating deviation inflates to reflect growing uncertainty.
+    pub fn update(&self, games: &[(Glicko2Rating, f64)]) -> Self {
+        let mu = self.mu();
+        let phi = self.phi();
+
+        if games.is_empty() {
+            let phi_star = (phi.powi(2) + self.volatility.powi(2)).sqrt();
+            return Self::from_internal(mu, phi_star, self.volatility);
+        }
+
+        let (v_inv, delta_sum) =
+            games
+                .iter()
+                .fold((0.0, 0.0), |(v_acc, d_acc), (opponent, score)| {
+                    let g = g_phi(opponent.phi());
+                    let e = expected_score(mu, opponent.mu(), g);
+                    (v_acc + g.powi(2) * e * (1.0 - e), d_acc + g * (score - e))
+                });
+
+        let v = 1.0 / v_inv;
+        let delta = v * delta_sum;
+
+        let new_volatility = solve_volatility(delta, phi, v, self.volatility);
+
+        let phi_star = (phi.powi(2) + new_volatility.powi(2)).sqrt();
+        let new_phi = 1.0 / (1.0 / phi_star.powi(2) + 1.0 / v).sqrt();
+        let new_mu = mu + new_phi.powi(2) * delta_sum;
+
+        Self::from_internal(new_mu, new_phi, new_volatility)
+    }
+}
+
+/// The Glicko-2 "g" function, down-weighting a game by the opponent's uncertainty.
+fn g_phi(phi: f64) -> f64 {
+    1.0 / (1.0 + 3.0 * phi.powi(2) / PI.powi(2)).sqrt()
+}
+
+/// Expected score of a player at `mu` against an opponent at `opponent_mu`, on the internal scale.
+fn expected_score(mu: f64, opponent_mu: f64, g_opponent: f64) -> f64 {
+    1.0 / (1.0 + (-g_opponent * (mu - opponent_mu)).exp())
+}
+
+/// Solve for the new volatility via the Illinois algorithm (regula falsi with bisection
+/// fallback), per step 5 of Glickman's Glicko-2 paper.
+fn solve_volatility(delta: f64, phi: f64, v: f64, sigma: f64) -> f64 {
+    let f = |x: f64| {
+        let ex = x.exp();
+        (ex * (delta.powi(2) - phi.powi(2) - v - ex)) / (2.0 * (phi.powi(2) + v + ex).powi(2))
+            - (x - (sigma.powi(2)).ln()) / TAU.powi(2)
+    };
+
+    let a = (sigma.powi(2)).ln();
+    let mut big_a = a;
+    let mut big_b = if delta.powi(2) > phi.powi(2) + v {
+        (delta.powi(2) - phi.powi(2) - v).ln()
+    } else {
+        let mut k = 1.0;
+        while f(a - k * TAU) < 0.0 {
+            k += 1.0;
+        }
+        a - k * TAU
+    };
+
+    let mut f_a = f(big_a);
+    let mut f_b = f(big_b);
+
+    while (big_b - big_a).abs() > VOLATILITY_EPSILON {
+        let big_c = big_a + (big_a - big_b) * f_a / (f_b - f_a);
+        let f_c = f(big_c);
+
+        if f_c * f_b < 0.0 {
+            big_a = big_b;
+            f_a = f_b;
+        } else {
+            f_a /= 2.0;
+        }
+
+        big_b = big_c;
+        f_b = f_c;
+    }
+
+    (big_a / 2.0).exp()
+}
+
+/// Best (lowest, non-DNF) single result each competitor achieved on a given date, used as the
+/// ranking key for that rating period.
+fn rank_period(
+    grouped: &HashMap<String, HashMap<NaiveDate, Vec<i32>>>,
+    date: NaiveDate,
+) -> Vec<(String, i32)> {
+    let mut present: Vec<(String, i32)> = grouped
+        .iter()
+        .filter_map(|(id, by_date)| {
+            by_date.get(&date).and_then(|times| {
+                times
+                    .iter()
+                    .copied()
+                    .filter(|&t| t > 0)
+                    .min()
+                    .map(|best| (id.clone(), best))
+            })
+        })
+        .collect();
+
+    present.sort_by_key(|&(_, time)| time);
+    present
+}
+
+/// Compute a Glicko-2 rating per competitor from their historical finishing order, treating each
+/// competition date present in `grouped` as one rating period.
+pub fn compute_field_ratings(
+    grouped: &HashMap<String, HashMap<NaiveDate, Vec<i32>>>,
+) -> HashMap<String, Glicko2Rating> {
+    let mut ratings: HashMap<String, Glicko2Rating> = grouped
+        .keys()
+        .map(|id| (id.clone(), Glicko2Rating::default()))
+        .collect();
+
+    let dates: BTreeSet<NaiveDate> = grouped.values().flat_map(|m| m.keys().copied()).collect();
+
+    for date in dates {
+        let present = rank_period(grouped, date);
+        let snapshot = ratings.clone();
+
+        let mut games: HashMap<String, Vec<(Glicko2Rating, f64)>> = HashMap::new();
+        for (i, (id_i, time_i)) in present.iter().enumerate() {
+            for (j, (id_j, time_j)) in present.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+
+                let score = match time_i.cmp(time_j) {
+                    Ordering::Less => 1.0,
+                    Ordering::Greater => 0.0,
+                    Ordering::Equal => 0.5,
+                };
+
+                games
+                    .entry(id_i.clone())
+                    .or_default()
+                    .push((snapshot[id_j], score));
+            }
+        }
+
+        for (id, rating) in ratings.iter_mut() {
+            let period_games = games.get(id).map(Vec::as_slice).unwrap_or(&[]);
+            *rating = rating.update(period_games);
+        }
+    }
+
+    ratings
+}
+
+/// Probability that `a` beats `b` in a head-to-head, per the Glicko-2 expected score using their
+/// combined rating uncertainty.
+pub fn win_probability(a: &Glicko2Rating, b: &Glicko2Rating) -> f64 {
+    let combined_phi = (a.phi().powi(2) + b.phi().powi(2)).sqrt();
+    expected_score(a.mu(), b.mu(), g_phi(combined_phi))
+}
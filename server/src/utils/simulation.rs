@@ -4,34 +4,189 @@ use crate::utils::charts::{
 };
 use crate::utils::types::{FullHistogramChartData, SimulationEndpointResults};
 
-use super::competitor::Competitor;
+use super::analytic;
+use super::competitor::{Competitor, decode_multi_blind_value};
 use super::constants::*;
-use super::types::{CompetitorSimulationResult, CompetitorStats, EventType};
+use super::metrics;
+use super::quantile::QuantileSummary;
+use super::stats::{standard_error, wilson_half_width, wilson_interval};
+use super::types::{
+    CompetitorSimulationResult, CompetitorStats, ConfidenceInterval, EventType, MomentSummary,
+    PercentileSummary,
+};
 
 use itertools::izip;
 use rand::prelude::*;
+use rand_chacha::ChaCha20Rng;
 use rand_distr::Normal;
+use rayon::prelude::*;
 use std::collections::HashMap;
 
-const HIST_INCLUDE_THRESHOLD: f64 = 0.0001; // This is the max resolution we show on the charts.
+// This is the max resolution we show on the charts.
+pub(crate) const HIST_INCLUDE_THRESHOLD: f64 = 0.0001;
+
+// Adaptive stopping: run this many rounds per batch, then check whether the current leader's
+// win-chance confidence interval has tightened below the caller's tolerance before continuing.
+const ADAPTIVE_BATCH_SIZE: u32 = 10_000;
+
+// Fixed shard count for parallelizing a batch, independent of the host's core count: the round
+// split and per-shard seeds must stay the same on every machine so a given `seed` reproduces the
+// exact same result regardless of where it's run, not just regardless of scheduling order.
+const SHARD_COUNT: usize = 16;
+
+// z-score for a 95% confidence interval.
+const CONFIDENCE_Z: f64 = 1.96;
+
+/// 95% interval for a mean estimated from `n` samples with known `sum` and `sum_sq` (sum of
+/// squares), via `mean ± z * s / sqrt(n)` using the running sample variance. Used for
+/// `expected_rank`, whose per-iteration value (the competitor's rank that round) isn't a
+/// proportion, so Wilson doesn't apply.
+fn mean_interval(sum: f64, sum_sq: f64, n: u32, bounds: (f64, f64)) -> ConfidenceInterval {
+    if n == 0 {
+        return ConfidenceInterval {
+            lower: bounds.0,
+            upper: bounds.1,
+        };
+    }
+    let n_f = n as f64;
+    let mean = sum / n_f;
+    let variance = (sum_sq / n_f - mean * mean).max(0.0);
+    let half_width = CONFIDENCE_Z * (variance / n_f).sqrt();
+
+    ConfidenceInterval {
+        lower: (mean - half_width).max(bounds.0),
+        upper: (mean + half_width).min(bounds.1),
+    }
+}
+
+/// Standard error of a mean estimated from `n` samples with known `sum` and `sum_sq`: the running
+/// sample standard deviation divided by sqrt(n).
+fn mean_se(sum: f64, sum_sq: f64, n: u32) -> f64 {
+    if n == 0 {
+        return 0.0;
+    }
+    let n_f = n as f64;
+    let mean = sum / n_f;
+    let variance = (sum_sq / n_f - mean * mean).max(0.0);
+    (variance / n_f).sqrt()
+}
+
+/// Read the p5/p50/p90/p95 markers off a [`QuantileSummary`], each within the summary's error
+/// bound rather than snapped to the histogram's fixed resolution.
+fn percentile_summary(summary: &QuantileSummary) -> PercentileSummary {
+    PercentileSummary {
+        p5: summary.query(0.05),
+        p50: summary.query(0.50),
+        p90: summary.query(0.90),
+        p95: summary.query(0.95),
+    }
+}
+
+/// Online mean/variance/min/max accumulator (`sum`/`sum_sq`/`min`/`max`), merged across shards the
+/// same way as the rank totals and quantile summaries.
+#[derive(Clone)]
+struct MomentAcc {
+    count: u64,
+    sum: f64,
+    sum_sq: f64,
+    min: i32,
+    max: i32,
+}
+
+impl MomentAcc {
+    fn new() -> Self {
+        Self {
+            count: 0,
+            sum: 0.0,
+            sum_sq: 0.0,
+            min: i32::MAX,
+            max: i32::MIN,
+        }
+    }
+
+    fn insert(&mut self, value: i32) {
+        self.count += 1;
+        self.sum += value as f64;
+        self.sum_sq += (value as f64).powi(2);
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
 
-fn truncate_num(input: i32, is_fmc: bool) -> i32 {
+    fn merge(&mut self, other: &MomentAcc) {
+        self.count += other.count;
+        self.sum += other.sum;
+        self.sum_sq += other.sum_sq;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    /// `None` if nothing was ever recorded.
+    fn into_summary(self) -> Option<MomentSummary> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let mean = self.sum / self.count as f64;
+        // Guard against tiny negative variance from floating-point cancellation.
+        let variance = (self.sum_sq / self.count as f64 - mean * mean).max(0.0);
+
+        Some(MomentSummary {
+            mean,
+            std_dev: variance.sqrt(),
+            min: self.min,
+            max: self.max,
+        })
+    }
+}
+
+// Multi-Blind results are ranked by (points desc, time asc). We fold both into a single
+// sortable i32 so the rest of run_simulations can keep comparing "lower is better" uniformly:
+// higher points move the key down by a full time-scale step, and ties break on time ascending.
+const MULTI_BLIND_MAX_POINTS: i32 = 99;
+const MULTI_BLIND_TIME_SCALE: i32 = 100_000; // exceeds any plausible attempt time in seconds
+const MULTI_BLIND_DNF_KEY: i32 = i32::MAX;
+
+pub(crate) fn truncate_num(input: i32, is_fmc: bool) -> i32 {
     if is_fmc { input } else { (input / 10) * 10 }
 }
 
 /// Result type for simulation runs containing all computed statistics
 pub struct SimulationResults {
     pub win_chance: Vec<f64>,
+    /// Binomial standard error of `win_chance`, i.e. sqrt(p(1-p)/N) over `simulation_count`.
+    pub win_chance_se: Vec<f64>,
+    /// 95% Wilson score interval for `win_chance`.
+    pub win_chance_ci: Vec<ConfidenceInterval>,
     pub pod_chance: Vec<f64>,
+    /// Binomial standard error of `pod_chance`.
+    pub pod_chance_se: Vec<f64>,
+    /// 95% Wilson score interval for `pod_chance`.
+    pub pod_chance_ci: Vec<ConfidenceInterval>,
     pub expected_ranks: Vec<f64>,
+    /// Standard error of `expected_ranks`, from the running per-iteration rank variance.
+    pub expected_rank_se: Vec<f64>,
+    /// 95% interval for `expected_ranks`, from the running per-iteration rank variance.
+    pub expected_rank_ci: Vec<ConfidenceInterval>,
     pub rank_dists: Vec<Vec<f64>>,
     pub hist_singles: Vec<HashMap<i32, f64>>,
     pub hist_averages: Vec<HashMap<i32, f64>>,
+    /// Approximate p5/p50/p90/p95 of each competitor's individual solve/attempt times.
+    pub single_percentiles: Vec<PercentileSummary>,
+    /// Approximate p5/p50/p90/p95 of each competitor's round result (average/mean/best).
+    pub average_percentiles: Vec<PercentileSummary>,
+    /// Mean/std-dev/min/max of each competitor's individual solve/attempt times.
+    pub single_moments: Vec<Option<MomentSummary>>,
+    /// Mean/std-dev/min/max of each competitor's round result (average/mean/best).
+    pub average_moments: Vec<Option<MomentSummary>>,
+    /// Actual number of rounds simulated. Equal to `max_simulation_count` unless adaptive
+    /// stopping (`target_win_chance_ci_half_width`) cut the run short, and 0 for a result from
+    /// the analytic path, which simulates no rounds.
+    pub simulation_count: u32,
 }
 
-pub fn generate_skewnorm_value(
+pub fn generate_skewnorm_value<R: Rng + ?Sized>(
     stats: &CompetitorStats,
-    rng: &mut ThreadRng,
+    rng: &mut R,
     include_dnf: bool,
 ) -> i32 {
     let normal = Normal::new(0.0, 1.0).unwrap();
@@ -70,16 +225,94 @@ pub fn num_solves(event_type: EventType) -> usize {
         EventType::Mo3 => MO3_SOLVE_COUNT,
         EventType::Fmc => MO3_SOLVE_COUNT,
         EventType::Bo3 => BO3_SOLVE_COUNT,
+        EventType::MultiBlind => 1,
+    }
+}
+
+/// Fold a Multi-Blind (points, time) result into a single ascending sort key: higher points
+/// rank better, ties broken by lower time, matching the WCA tie-break rule.
+fn multi_blind_sort_key(points: i32, time_seconds: i32) -> i32 {
+    let points = points.clamp(1, MULTI_BLIND_MAX_POINTS);
+    let time_seconds = time_seconds.clamp(0, MULTI_BLIND_TIME_SCALE - 1);
+    (MULTI_BLIND_MAX_POINTS - points) * MULTI_BLIND_TIME_SCALE + time_seconds
+}
+
+/// Inverse of [`multi_blind_sort_key`]: recover the `points` the key was packed from, so
+/// downstream per-round reporting (histogram/quantiles/moments) can read a real points value
+/// off the sort key instead of the ~10^6-scale key itself.
+fn multi_blind_decode_points(key: i32) -> i32 {
+    MULTI_BLIND_MAX_POINTS - key / MULTI_BLIND_TIME_SCALE
+}
+
+fn simulate_multi_blind_round<R: Rng + ?Sized>(
+    competitor: &Competitor,
+    rng: &mut R,
+    include_dnf: bool,
+    hist_single: &mut HashMap<i32, i32>,
+    single_quantiles: &mut QuantileSummary,
+    single_moments: &mut MomentAcc,
+) -> i32 {
+    let manual_result = competitor.entered_results.first().copied().unwrap_or(0);
+    if manual_result != 0 {
+        // `manual_result` is the raw WCA-encoded value (scale ~1e8), not a `multi_blind_sort_key`
+        // (scale ~1e5-1e7) -- it has to be decoded and repacked through the same key as simulated
+        // competitors, or it ranks as if it had virtually no points at all.
+        return if manual_result < 0 {
+            MULTI_BLIND_DNF_KEY
+        } else {
+            match decode_multi_blind_value(manual_result) {
+                Some((points, time_seconds)) => multi_blind_sort_key(points, time_seconds),
+                None => MULTI_BLIND_DNF_KEY,
+            }
+        };
     }
+
+    let Some(stats) = &competitor.multi_blind_stats else {
+        return MULTI_BLIND_DNF_KEY;
+    };
+
+    if include_dnf && rng.random::<f32>() < stats.dnf_rate {
+        return MULTI_BLIND_DNF_KEY;
+    }
+
+    // Points and time are drawn independently from their own fitted marginals -- `MultiBlindStats`
+    // only tracks each one's mean/stdev, not a points/time covariance, so there's nothing to
+    // condition the time draw on.
+    let points_dist = Normal::new(stats.mean_points as f64, stats.points_stdev.max(0.5) as f64)
+        .expect("multi-blind points stdev is finite and non-negative");
+    let points = (points_dist.sample(rng).round() as i32).clamp(1, MULTI_BLIND_MAX_POINTS);
+
+    let time_dist = Normal::new(stats.mean_time as f64, stats.time_stdev.max(1.0) as f64)
+        .expect("multi-blind time stdev is finite and non-negative");
+    let time_seconds = (time_dist.sample(rng).round() as i32).max(1);
+
+    *hist_single.entry(time_seconds).or_default() += 1;
+    single_quantiles.insert(time_seconds);
+    single_moments.insert(time_seconds);
+
+    multi_blind_sort_key(points, time_seconds)
 }
 
-pub fn simulate_round(
+pub fn simulate_round<R: Rng + ?Sized>(
     competitor: &Competitor,
     event_type: &EventType,
-    rng: &mut ThreadRng,
+    rng: &mut R,
     include_dnf: bool,
     hist_single: &mut HashMap<i32, i32>,
+    single_quantiles: &mut QuantileSummary,
+    single_moments: &mut MomentAcc,
 ) -> i32 {
+    if matches!(event_type, EventType::MultiBlind) {
+        return simulate_multi_blind_round(
+            competitor,
+            rng,
+            include_dnf,
+            hist_single,
+            single_quantiles,
+            single_moments,
+        );
+    }
+
     let count = num_solves(*event_type);
 
     let mut solves = [DNF_VALUE; 5];
@@ -106,6 +339,8 @@ pub fn simulate_round(
                 *hist_single
                     .entry(truncate_num(*solve, matches!(event_type, EventType::Fmc)))
                     .or_default() += 1;
+                single_quantiles.insert(*solve);
+                single_moments.insert(*solve);
             }
         }
     }
@@ -172,26 +407,83 @@ fn generate_histogram(
         .collect()
 }
 
-pub fn run_simulations(
+// Below this count the Monte Carlo loop is already cheap enough that switching backends isn't
+// worth it; above it, a small enough field is better served by the exact analytic computation.
+const ANALYTIC_MIN_SIMULATION_COUNT: u32 = 50_000;
+const ANALYTIC_MAX_FIELD_SIZE: usize = 8;
+
+/// Per-shard accumulators for one independent slice of rounds, reduced by element-wise summation
+/// (and [`QuantileSummary::merge`] for the two quantile digests) into the run's totals once the
+/// shard finishes. Each shard draws from its own RNG stream, so running the same total round
+/// count across a different number of shards does not change any other shard's output.
+struct ShardAccumulator {
+    win_counts: Vec<u32>,
+    pod_counts: Vec<u32>,
+    total_ranks: Vec<u32>,
+    total_rank_sq: Vec<f64>,
+    rank_dist_count: Vec<Vec<u32>>,
+    hist_average_map: Vec<HashMap<i32, i32>>,
+    hist_single_map: Vec<HashMap<i32, i32>>,
+    single_quantiles: Vec<QuantileSummary>,
+    average_quantiles: Vec<QuantileSummary>,
+    single_moments: Vec<MomentAcc>,
+    average_moments: Vec<MomentAcc>,
+}
+
+impl ShardAccumulator {
+    fn new(num_competitors: usize) -> Self {
+        Self {
+            win_counts: vec![0; num_competitors],
+            pod_counts: vec![0; num_competitors],
+            total_ranks: vec![0; num_competitors],
+            total_rank_sq: vec![0.0; num_competitors],
+            rank_dist_count: vec![vec![0; num_competitors]; num_competitors],
+            hist_average_map: vec![HashMap::new(); num_competitors],
+            hist_single_map: vec![HashMap::new(); num_competitors],
+            single_quantiles: (0..num_competitors).map(|_| QuantileSummary::new()).collect(),
+            average_quantiles: (0..num_competitors).map(|_| QuantileSummary::new()).collect(),
+            single_moments: (0..num_competitors).map(|_| MomentAcc::new()).collect(),
+            average_moments: (0..num_competitors).map(|_| MomentAcc::new()).collect(),
+        }
+    }
+
+    fn merge_into(self, into: &mut ShardAccumulator) {
+        for idx in 0..into.win_counts.len() {
+            into.win_counts[idx] += self.win_counts[idx];
+            into.pod_counts[idx] += self.pod_counts[idx];
+            into.total_ranks[idx] += self.total_ranks[idx];
+            into.total_rank_sq[idx] += self.total_rank_sq[idx];
+            for r in 0..into.rank_dist_count[idx].len() {
+                into.rank_dist_count[idx][r] += self.rank_dist_count[idx][r];
+            }
+            for (&k, &v) in &self.hist_average_map[idx] {
+                *into.hist_average_map[idx].entry(k).or_default() += v;
+            }
+            for (&k, &v) in &self.hist_single_map[idx] {
+                *into.hist_single_map[idx].entry(k).or_default() += v;
+            }
+            into.single_quantiles[idx].merge(&self.single_quantiles[idx]);
+            into.average_quantiles[idx].merge(&self.average_quantiles[idx]);
+            into.single_moments[idx].merge(&self.single_moments[idx]);
+            into.average_moments[idx].merge(&self.average_moments[idx]);
+        }
+    }
+}
+
+/// Run `round_count` rounds for `competitors` against a single RNG stream seeded from
+/// `shard_seed`, producing one shard's worth of accumulated statistics.
+fn run_shard(
     competitors: &[Competitor],
     event_type: &EventType,
     include_dnf: bool,
-    simulation_count: u32,
-) -> SimulationResults {
+    round_count: u32,
+    shard_seed: u64,
+) -> ShardAccumulator {
     let num_competitors = competitors.len();
+    let mut acc = ShardAccumulator::new(num_competitors);
+    let mut rng = ChaCha20Rng::seed_from_u64(shard_seed);
 
-    // Output structures
-    let mut win_counts = vec![0u32; num_competitors];
-    let mut pod_counts = vec![0u32; num_competitors];
-    let mut total_ranks = vec![0u32; num_competitors];
-    let mut rank_dist_count = vec![vec![0u32; num_competitors]; num_competitors];
-    let mut hist_average_map: Vec<HashMap<i32, i32>> = vec![HashMap::new(); num_competitors];
-    let mut hist_single_map: Vec<HashMap<i32, i32>> = vec![HashMap::new(); num_competitors];
-
-    let mut rng = rand::rng();
-
-    for _ in 0..simulation_count {
-        // Run one round for everyone
+    for _ in 0..round_count {
         let mut round_results: Vec<(usize, i32)> = competitors
             .iter()
             .enumerate()
@@ -201,14 +493,26 @@ pub fn run_simulations(
                     event_type,
                     &mut rng,
                     include_dnf,
-                    &mut hist_single_map[idx],
+                    &mut acc.hist_single_map[idx],
+                    &mut acc.single_quantiles[idx],
+                    &mut acc.single_moments[idx],
                 );
 
                 // Add to AVERAGE histogram
-                if res != DNF_VALUE {
-                    *hist_average_map[idx]
-                        .entry(truncate_num(res, matches!(event_type, EventType::Fmc)))
-                        .or_default() += 1;
+                if res != DNF_VALUE && res != MULTI_BLIND_DNF_KEY {
+                    if matches!(event_type, EventType::MultiBlind) {
+                        // `res` is the packed (points, time) sort key, not a reportable value on
+                        // its own -- decode back to the points it was built from.
+                        let points = multi_blind_decode_points(res);
+                        *acc.hist_average_map[idx].entry(points).or_default() += 1;
+                        acc.average_quantiles[idx].insert(points);
+                        acc.average_moments[idx].insert(points);
+                    } else {
+                        let truncated = truncate_num(res, matches!(event_type, EventType::Fmc));
+                        *acc.hist_average_map[idx].entry(truncated).or_default() += 1;
+                        acc.average_quantiles[idx].insert(truncated);
+                        acc.average_moments[idx].insert(truncated);
+                    }
                 }
 
                 (idx, res)
@@ -221,16 +525,137 @@ pub fn run_simulations(
         // Update Stats
         for (rank, &(original_idx, _)) in round_results.iter().enumerate() {
             if rank == 0 {
-                win_counts[original_idx] += 1;
+                acc.win_counts[original_idx] += 1;
             }
             if rank < 3 {
-                pod_counts[original_idx] += 1;
+                acc.pod_counts[original_idx] += 1;
             }
-            total_ranks[original_idx] += (rank as u32) + 1;
-            rank_dist_count[original_idx][rank] += 1;
+            let placed_rank = (rank as u32) + 1;
+            acc.total_ranks[original_idx] += placed_rank;
+            acc.total_rank_sq[original_idx] += (placed_rank as f64).powi(2);
+            acc.rank_dist_count[original_idx][rank] += 1;
         }
     }
 
+    acc
+}
+
+/// Split `total` rounds as evenly as possible across `shards` shards (the first `total % shards`
+/// shards get one extra round), so shard sizes are stable regardless of how rayon schedules them.
+fn split_rounds(total: u32, shards: usize) -> Vec<u32> {
+    let base = total / shards as u32;
+    let remainder = total % shards as u32;
+    (0..shards as u32)
+        .map(|i| base + u32::from(i < remainder))
+        .collect()
+}
+
+/// Run Monte Carlo simulations for `competitors`, up to `max_simulation_count` rounds.
+///
+/// If `target_win_chance_ci_half_width` is given, rounds are run in batches of
+/// [`ADAPTIVE_BATCH_SIZE`] and the run stops early once the current leader's (the competitor
+/// with the most wins so far) win-chance 95% Wilson interval half-width drops to or below the
+/// target, rather than always spending the full `max_simulation_count`. The actual number of
+/// rounds run is reported as `SimulationResults::simulation_count`.
+///
+/// Each batch is split across [`SHARD_COUNT`] shards, each drawing from its own `ChaCha20Rng`
+/// derived from a base seed. When `seed` is given it is that base, so the exact same
+/// win/pod/rank numbers come back for the same inputs every time (golden-file tests, shareable
+/// "scenario" links, and a cache key that's actually correct to hash on). Without a seed, the
+/// base seed is drawn from the thread-local RNG.
+///
+/// For small best-of-k fields with a large `max_simulation_count`, this instead delegates to
+/// [`analytic::compute_analytic`], which computes the same statistics exactly via numeric
+/// quadrature rather than sampling (see [`ANALYTIC_MIN_SIMULATION_COUNT`], [`ANALYTIC_MAX_FIELD_SIZE`]).
+pub fn run_simulations(
+    competitors: &[Competitor],
+    event_type: &EventType,
+    include_dnf: bool,
+    max_simulation_count: u32,
+    target_win_chance_ci_half_width: Option<f64>,
+    seed: Option<u64>,
+) -> SimulationResults {
+    if max_simulation_count >= ANALYTIC_MIN_SIMULATION_COUNT
+        && competitors.len() <= ANALYTIC_MAX_FIELD_SIZE
+        && analytic::supports_analytic(event_type)
+    {
+        if let Some(results) = analytic::compute_analytic(competitors, event_type, include_dnf) {
+            return results;
+        }
+    }
+
+    let num_competitors = competitors.len();
+    let mut totals = ShardAccumulator::new(num_competitors);
+
+    // Every shard derives its seed from this base, so a given `seed` reproduces the exact same
+    // per-shard streams (and thus the exact same result) regardless of the host's thread count.
+    let base_seed = seed.unwrap_or_else(|| rand::rng().random::<u64>());
+    let mut shards_run: u64 = 0;
+    let mut completed: u32 = 0;
+
+    while completed < max_simulation_count {
+        let batch_size = match target_win_chance_ci_half_width {
+            Some(_) => ADAPTIVE_BATCH_SIZE.min(max_simulation_count - completed),
+            None => max_simulation_count,
+        };
+
+        // Fixed regardless of `rayon::current_num_threads()` -- rayon still schedules this many
+        // shards across however many worker threads the host has, but the shard boundaries and
+        // seeds themselves never depend on that count.
+        let shard_count = SHARD_COUNT.min(batch_size.max(1) as usize);
+
+        let shard_results: Vec<ShardAccumulator> = split_rounds(batch_size, shard_count)
+            .into_par_iter()
+            .enumerate()
+            .map(|(i, rounds)| {
+                let shard_seed = base_seed ^ (shards_run + i as u64);
+                run_shard(competitors, event_type, include_dnf, rounds, shard_seed)
+            })
+            .collect();
+        shards_run += shard_count as u64;
+
+        for shard in shard_results {
+            shard.merge_into(&mut totals);
+        }
+
+        completed += batch_size;
+
+        if let Some(tolerance) = target_win_chance_ci_half_width {
+            let leader_idx = totals
+                .win_counts
+                .iter()
+                .enumerate()
+                .max_by_key(|&(_, &w)| w)
+                .map(|(idx, _)| idx);
+
+            let leader_half_width = leader_idx
+                .map(|idx| {
+                    wilson_half_width(totals.win_counts[idx] as f64 / completed as f64, completed)
+                })
+                .unwrap_or(0.0);
+
+            if leader_half_width <= tolerance {
+                break;
+            }
+        }
+    }
+
+    let ShardAccumulator {
+        win_counts,
+        pod_counts,
+        total_ranks,
+        total_rank_sq,
+        rank_dist_count,
+        hist_average_map,
+        hist_single_map,
+        single_quantiles,
+        average_quantiles,
+        single_moments,
+        average_moments,
+    } = totals;
+
+    let simulation_count = completed;
+
     let rank_dists = rank_dist_count
         .into_iter()
         .map(|counts| {
@@ -244,22 +669,83 @@ pub fn run_simulations(
     let hist_singles = generate_histogram(hist_single_map, simulation_count, *event_type, true);
     let hist_averages = generate_histogram(hist_average_map, simulation_count, *event_type, false);
 
+    let win_chance: Vec<f64> = win_counts
+        .into_iter()
+        .map(|v| v as f64 / simulation_count as f64)
+        .collect();
+    let pod_chance: Vec<f64> = pod_counts
+        .into_iter()
+        .map(|v| v as f64 / simulation_count as f64)
+        .collect();
+
+    let win_chance_se = win_chance
+        .iter()
+        .map(|&p| standard_error(p, simulation_count))
+        .collect();
+    let pod_chance_se = pod_chance
+        .iter()
+        .map(|&p| standard_error(p, simulation_count))
+        .collect();
+
+    let win_chance_ci = win_chance
+        .iter()
+        .map(|&p| wilson_interval(p, simulation_count))
+        .collect();
+    let pod_chance_ci = pod_chance
+        .iter()
+        .map(|&p| wilson_interval(p, simulation_count))
+        .collect();
+
+    let expected_ranks: Vec<f64> = total_ranks
+        .iter()
+        .map(|&v| v as f64 / simulation_count as f64)
+        .collect();
+
+    let expected_rank_se = izip!(&total_ranks, &total_rank_sq)
+        .map(|(&sum, &sum_sq)| mean_se(sum as f64, sum_sq, simulation_count))
+        .collect();
+
+    let expected_rank_ci = izip!(&total_ranks, &total_rank_sq)
+        .map(|(&sum, &sum_sq)| {
+            mean_interval(
+                sum as f64,
+                sum_sq,
+                simulation_count,
+                (1.0, num_competitors as f64),
+            )
+        })
+        .collect();
+
+    let single_percentiles = single_quantiles.iter().map(percentile_summary).collect();
+    let average_percentiles = average_quantiles.iter().map(percentile_summary).collect();
+
+    let single_moments = single_moments
+        .into_iter()
+        .map(MomentAcc::into_summary)
+        .collect();
+    let average_moments = average_moments
+        .into_iter()
+        .map(MomentAcc::into_summary)
+        .collect();
+
     SimulationResults {
-        win_chance: win_counts
-            .into_iter()
-            .map(|v| v as f64 / simulation_count as f64)
-            .collect(),
-        pod_chance: pod_counts
-            .into_iter()
-            .map(|v| v as f64 / simulation_count as f64)
-            .collect(),
-        expected_ranks: total_ranks
-            .into_iter()
-            .map(|v| v as f64 / simulation_count as f64)
-            .collect(),
+        win_chance,
+        win_chance_se,
+        win_chance_ci,
+        pod_chance,
+        pod_chance_se,
+        pod_chance_ci,
+        expected_ranks,
+        expected_rank_se,
+        expected_rank_ci,
         rank_dists,
         hist_singles,
         hist_averages,
+        single_percentiles,
+        average_percentiles,
+        single_moments,
+        average_moments,
+        simulation_count,
     }
 }
 
@@ -267,14 +753,35 @@ pub fn format_results(
     competitors: Vec<Competitor>,
     results: SimulationResults,
     is_fmc: bool,
+) -> SimulationEndpointResults {
+    metrics::time_phase("format_results", || {
+        format_results_inner(competitors, results, is_fmc)
+    })
+}
+
+fn format_results_inner(
+    competitors: Vec<Competitor>,
+    results: SimulationResults,
+    is_fmc: bool,
 ) -> SimulationEndpointResults {
     let SimulationResults {
         win_chance,
+        win_chance_se,
+        win_chance_ci,
         pod_chance,
+        pod_chance_se,
+        pod_chance_ci,
         expected_ranks,
+        expected_rank_se,
+        expected_rank_ci,
         rank_dists,
         hist_singles,
         hist_averages,
+        single_percentiles,
+        average_percentiles,
+        single_moments,
+        average_moments,
+        simulation_count: _,
     } = results;
 
     let hist_single_data = hist_singles
@@ -315,27 +822,70 @@ pub fn format_results(
     let competitor_results = izip!(
         competitors,
         expected_ranks,
+        expected_rank_se,
+        expected_rank_ci,
         win_chance,
+        win_chance_se,
+        win_chance_ci,
         pod_chance,
+        pod_chance_se,
+        pod_chance_ci,
         &hist_singles,
-        &hist_averages
+        &hist_averages,
+        single_percentiles,
+        average_percentiles,
+        single_moments,
+        average_moments
+    )
+    .map(
+        |(
+            comp,
+            exp_rank,
+            exp_rank_se,
+            exp_rank_ci,
+            win,
+            win_se,
+            win_ci,
+            pod,
+            pod_se,
+            pod_ci,
+            h_single,
+            h_avg,
+            single_percentiles,
+            average_percentiles,
+            single_moments,
+            average_moments,
+        )| {
+            let stats = comp.stats.as_ref();
+            let multi_blind_stats = comp.multi_blind_stats.as_ref();
+
+            let histogram = create_invidual_histogram_chart(h_single, h_avg, is_fmc);
+
+            CompetitorSimulationResult {
+                id: comp.id,
+                name: comp.name,
+                expected_rank: exp_rank,
+                expected_rank_se: exp_rank_se,
+                expected_rank_ci: exp_rank_ci,
+                win_chance: win,
+                win_chance_se: win_se,
+                win_chance_ci: win_ci,
+                pod_chance: pod,
+                pod_chance_se: pod_se,
+                pod_chance_ci: pod_ci,
+                sample_size: stats.map(|s| s.num_non_dnf_results).unwrap_or(0),
+                mean_no_dnf: stats
+                    .map(|s| s.mean as u32)
+                    .or_else(|| multi_blind_stats.map(|s| s.mean_points as u32))
+                    .unwrap_or(0),
+                histogram,
+                single_percentiles,
+                average_percentiles,
+                single_moments,
+                average_moments,
+            }
+        },
     )
-    .map(|(comp, exp_rank, win, pod, h_single, h_avg)| {
-        let stats = comp.stats.as_ref();
-
-        let histogram = create_invidual_histogram_chart(h_single, h_avg, is_fmc);
-
-        CompetitorSimulationResult {
-            id: comp.id,
-            name: comp.name,
-            expected_rank: exp_rank,
-            win_chance: win,
-            pod_chance: pod,
-            sample_size: stats.map(|s| s.num_non_dnf_results).unwrap_or(0),
-            mean_no_dnf: stats.map(|s| s.mean as u32).unwrap_or(0),
-            histogram,
-        }
-    })
     .collect();
 
     SimulationEndpointResults {
@@ -344,3 +894,91 @@ pub fn format_results(
         rank_histogram,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::types::DatedCompetitionResult;
+
+    fn make_competitor(name: &str, times: &[i32]) -> Competitor {
+        let results = vec![DatedCompetitionResult {
+            days_since: 0,
+            results: times.to_vec(),
+        }];
+        Competitor::new(name.to_string(), name.to_string(), results, 300.0, EventType::Ao5)
+    }
+
+    fn sample_field() -> Vec<Competitor> {
+        vec![
+            make_competitor("a", &[900, 950, 1000, 920, 980]),
+            make_competitor("b", &[1100, 1050, 1150, 1080, 1120]),
+            make_competitor("c", &[1000, 1000, 1000, 1000, 1000]),
+        ]
+    }
+
+    #[test]
+    fn run_simulations_is_deterministic_for_a_fixed_seed() {
+        // Stay below ANALYTIC_MIN_SIMULATION_COUNT so this exercises the seeded Monte Carlo
+        // path rather than the analytic quadrature path.
+        let first = run_simulations(&sample_field(), &EventType::Ao5, false, 1_000, None, Some(42));
+        let second = run_simulations(&sample_field(), &EventType::Ao5, false, 1_000, None, Some(42));
+
+        assert_eq!(first.win_chance, second.win_chance);
+        assert_eq!(first.pod_chance, second.pod_chance);
+        assert_eq!(first.expected_ranks, second.expected_ranks);
+        assert_eq!(first.simulation_count, second.simulation_count);
+    }
+
+    #[test]
+    fn run_simulations_differs_across_seeds() {
+        let a = run_simulations(&sample_field(), &EventType::Ao5, false, 1_000, None, Some(1));
+        let b = run_simulations(&sample_field(), &EventType::Ao5, false, 1_000, None, Some(2));
+
+        // Extremely unlikely for two different seeds to produce byte-identical win chances
+        // across every competitor in a non-degenerate field.
+        assert_ne!(a.win_chance, b.win_chance);
+    }
+
+    #[test]
+    fn run_simulations_honors_adaptive_stopping_tolerance() {
+        // A generous tolerance should let the adaptive-stopping loop break out well before the
+        // full simulation count is spent.
+        let results = run_simulations(
+            &sample_field(),
+            &EventType::Ao5,
+            false,
+            30_000,
+            Some(0.49),
+            Some(7),
+        );
+        assert!(results.simulation_count < 30_000);
+    }
+
+    #[test]
+    fn manual_multi_blind_entry_is_repacked_onto_the_simulated_sort_key_scale() {
+        // 5 points, 300 seconds, 0 missed, encoded per the WCA `333mbf` format:
+        // (99 - 5) * 1e7 + 300 * 100 = 940_030_000.
+        let raw = (99 - 5) * 10_000_000 + 300 * 100;
+        let mut competitor = make_competitor("manual", &[]);
+        competitor.entered_results = vec![raw];
+
+        let mut hist = HashMap::new();
+        let mut quantiles = QuantileSummary::new();
+        let mut moments = MomentAcc::new();
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+
+        let key = simulate_multi_blind_round(
+            &competitor,
+            &mut rng,
+            false,
+            &mut hist,
+            &mut quantiles,
+            &mut moments,
+        );
+
+        assert_eq!(key, multi_blind_sort_key(5, 300));
+        // On the old (un-repacked) scale this would have been ~940_030_000, which dwarfs every
+        // simulated competitor's key and always sorts last regardless of actual performance.
+        assert!(key < MULTI_BLIND_TIME_SCALE * MULTI_BLIND_MAX_POINTS);
+    }
+}
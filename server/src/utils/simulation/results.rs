@@ -1,16 +1,34 @@
 use crate::utils::charts::{
-    HistogramData, RankStats, create_full_histogram_chart, create_individual_histogram_chart,
-    generate_rank_chart,
+    HistogramData, MomentStats, RankStats, create_full_histogram_chart,
+    create_individual_histogram_chart, generate_rank_chart,
 };
 use crate::utils::competitor::Competitor;
+use crate::utils::metrics;
 use crate::utils::types::{
-    CompetitorSimulationResult, FullHistogramChartData, SimulationEndpointResults,
+    CompetitorSimulationResult, FullHistogramChartData, MomentSummary, PercentileSummary,
+    SimulationEndpointResults,
 };
 
+const P5: f64 = 0.05;
+const P50: f64 = 0.50;
+const P90: f64 = 0.90;
+const P95: f64 = 0.95;
+
+fn moment_summary(stats: Option<MomentStats>) -> Option<MomentSummary> {
+    stats.map(|s| MomentSummary {
+        mean: s.mean,
+        std_dev: s.std_dev,
+        min: s.min,
+        max: s.max,
+    })
+}
+
 pub struct SimulationResult {
     rank_stats: RankStats,
     hist_single: HistogramData,
     hist_average: HistogramData,
+    moments_single: Option<MomentStats>,
+    moments_average: Option<MomentStats>,
 }
 
 impl SimulationResult {
@@ -18,11 +36,15 @@ impl SimulationResult {
         rank_stats: RankStats,
         hist_single: HistogramData,
         hist_average: HistogramData,
+        moments_single: Option<MomentStats>,
+        moments_average: Option<MomentStats>,
     ) -> Self {
         Self {
             rank_stats,
             hist_single,
             hist_average,
+            moments_single,
+            moments_average,
         }
     }
 
@@ -49,12 +71,52 @@ impl SimulationResult {
     pub fn rank_stats(&self) -> &RankStats {
         &self.rank_stats
     }
+
+    /// p5/p50/p90/p95 over individual solve/attempt times.
+    pub fn single_percentiles(&self, is_fmc: bool) -> PercentileSummary {
+        PercentileSummary {
+            p5: self.hist_single.quantile(P5, is_fmc, false),
+            p50: self.hist_single.quantile(P50, is_fmc, false),
+            p90: self.hist_single.quantile(P90, is_fmc, false),
+            p95: self.hist_single.quantile(P95, is_fmc, false),
+        }
+    }
+
+    /// p5/p50/p90/p95 over the round result (average/mean/best, per event format).
+    pub fn average_percentiles(&self, is_fmc: bool) -> PercentileSummary {
+        PercentileSummary {
+            p5: self.hist_average.quantile(P5, is_fmc, true),
+            p50: self.hist_average.quantile(P50, is_fmc, true),
+            p90: self.hist_average.quantile(P90, is_fmc, true),
+            p95: self.hist_average.quantile(P95, is_fmc, true),
+        }
+    }
+
+    /// Mean/std-dev/min/max over individual solve/attempt times.
+    pub fn single_moments(&self) -> Option<MomentSummary> {
+        moment_summary(self.moments_single)
+    }
+
+    /// Mean/std-dev/min/max over the round result (average/mean/best, per event format).
+    pub fn average_moments(&self) -> Option<MomentSummary> {
+        moment_summary(self.moments_average)
+    }
 }
 
 pub fn format_results(
     competitors: Vec<Competitor>,
     results: Vec<SimulationResult>,
     is_fmc: bool,
+) -> SimulationEndpointResults {
+    metrics::time_phase("format_results", || {
+        format_results_inner(competitors, results, is_fmc)
+    })
+}
+
+fn format_results_inner(
+    competitors: Vec<Competitor>,
+    results: Vec<SimulationResult>,
+    is_fmc: bool,
 ) -> SimulationEndpointResults {
     let hist_single_data: Vec<(&str, &HistogramData)> = results
         .iter()
@@ -101,11 +163,21 @@ pub fn format_results(
                 id: comp.id,
                 name: comp.name,
                 expected_rank: res.expected_rank(),
+                expected_rank_se: res.rank_stats().expected_rank_se(),
+                expected_rank_ci: res.rank_stats().expected_rank_ci(),
                 win_chance: res.win_probability(),
+                win_chance_se: res.rank_stats().win_chance_se(),
+                win_chance_ci: res.rank_stats().win_chance_ci(),
                 pod_chance: res.podium_probability(),
+                pod_chance_se: res.rank_stats().pod_chance_se(),
+                pod_chance_ci: res.rank_stats().pod_chance_ci(),
                 sample_size: stats.map(|s| s.num_non_dnf_results).unwrap_or(0),
                 mean_no_dnf: stats.map(|s| s.mean as u32).unwrap_or(0),
                 histogram,
+                single_percentiles: res.single_percentiles(is_fmc),
+                average_percentiles: res.average_percentiles(is_fmc),
+                single_moments: res.single_moments(),
+                average_moments: res.average_moments(),
             }
         })
         .collect();
@@ -1,8 +1,10 @@
-use crate::utils::charts::{HistogramAccumulator, RankAccumulator};
+use crate::utils::charts::{HistogramAccumulator, MomentAccumulator, RankAccumulator};
 use crate::utils::competitor::{Competitor, CompetitorStats};
 use crate::utils::wca::{DNF_VALUE, EventType, calculate_average};
 use rand::prelude::*;
+use rand_chacha::ChaCha20Rng;
 use rand_distr::Normal;
+use rayon::prelude::*;
 
 use super::results::SimulationResult;
 
@@ -12,6 +14,8 @@ struct CompetitorAccumulator {
     hist_single: HistogramAccumulator,
     hist_average: HistogramAccumulator,
     ranks: RankAccumulator,
+    moments_single: MomentAccumulator,
+    moments_average: MomentAccumulator,
 }
 
 impl CompetitorAccumulator {
@@ -20,23 +24,38 @@ impl CompetitorAccumulator {
             hist_single: HistogramAccumulator::new(),
             hist_average: HistogramAccumulator::new(),
             ranks: RankAccumulator::new(num_competitors),
+            moments_single: MomentAccumulator::new(),
+            moments_average: MomentAccumulator::new(),
         }
     }
 
     fn record_single(&mut self, solve: i32, is_fmc: bool) {
         let hist_value = Self::truncate_for_histogram(solve, is_fmc);
         self.hist_single.record(hist_value);
+        self.moments_single.record(solve);
     }
 
     fn record_average(&mut self, solve: i32, is_fmc: bool) {
         let hist_value = Self::truncate_for_histogram(solve, is_fmc);
         self.hist_average.record(hist_value);
+        self.moments_average.record(solve);
     }
 
     fn add_rank(&mut self, rank: usize) {
         self.ranks.record_rank(rank);
     }
 
+    /// Fold another shard's accumulator into this one. Delegates to each field's own `merge`,
+    /// which are all associative and order-independent, so the result is deterministic
+    /// regardless of how the rayon thread pool schedules shards.
+    fn merge(&mut self, other: &Self) {
+        self.hist_single.merge(&other.hist_single);
+        self.hist_average.merge(&other.hist_average);
+        self.ranks.merge(&other.ranks);
+        self.moments_single.merge(&other.moments_single);
+        self.moments_average.merge(&other.moments_average);
+    }
+
     fn finalize(self, simulation_count: u32, event_type: &EventType) -> SimulationResult {
         let single_scale = 100 / event_type.num_solves() as i32;
 
@@ -49,6 +68,8 @@ impl CompetitorAccumulator {
             ),
             self.hist_average
                 .into_histogram_data(simulation_count, 100, HIST_INCLUDE_THRESHOLD),
+            self.moments_single.into_moment_stats(),
+            self.moments_average.into_moment_stats(),
         )
     }
 
@@ -57,7 +78,11 @@ impl CompetitorAccumulator {
     }
 }
 
-fn generate_skewnorm_value(stats: &CompetitorStats, rng: &mut ThreadRng, include_dnf: bool) -> i32 {
+fn generate_skewnorm_value(
+    stats: &CompetitorStats,
+    rng: &mut impl Rng,
+    include_dnf: bool,
+) -> i32 {
     let normal = Normal::new(0.0, 1.0).unwrap();
 
     if stats.location.is_nan() || stats.shape.is_nan() {
@@ -87,7 +112,7 @@ fn generate_skewnorm_value(stats: &CompetitorStats, rng: &mut ThreadRng, include
 fn simulate_round(
     competitor: &Competitor,
     event_type: &EventType,
-    rng: &mut rand::rngs::ThreadRng,
+    rng: &mut impl Rng,
     include_dnf: bool,
     acc: &mut CompetitorAccumulator,
 ) -> i32 {
@@ -120,20 +145,33 @@ fn simulate_round(
     calculate_average(&mut solves, *event_type)
 }
 
-pub fn run_simulations(
+/// Split `total` rounds as evenly as possible across `shards` shards (the first `total % shards`
+/// shards get one extra round), so shard sizes are stable regardless of how rayon schedules them.
+fn split_rounds(total: u32, shards: usize) -> Vec<u32> {
+    let base = total / shards as u32;
+    let remainder = total % shards as u32;
+    (0..shards as u32)
+        .map(|i| base + u32::from(i < remainder))
+        .collect()
+}
+
+/// Run `round_count` rounds for `competitors` against a single RNG stream seeded from
+/// `shard_seed`, producing one shard's worth of accumulated statistics.
+fn run_shard(
     competitors: &[Competitor],
     event_type: &EventType,
     include_dnf: bool,
-    simulation_count: u32,
-) -> Vec<SimulationResult> {
+    round_count: u32,
+    shard_seed: u64,
+) -> Vec<CompetitorAccumulator> {
     let num_competitors = competitors.len();
-    let mut rng = rand::rng();
+    let mut rng = ChaCha20Rng::seed_from_u64(shard_seed);
 
     let mut accumulators: Vec<CompetitorAccumulator> = (0..num_competitors)
         .map(|_| CompetitorAccumulator::new(num_competitors))
         .collect();
 
-    for _ in 0..simulation_count {
+    for _ in 0..round_count {
         let mut round_results: Vec<(usize, i32)> = Vec::with_capacity(num_competitors);
 
         for (idx, comp) in competitors.iter().enumerate() {
@@ -155,6 +193,45 @@ pub fn run_simulations(
         }
     }
 
+    accumulators
+}
+
+pub fn run_simulations(
+    competitors: &[Competitor],
+    event_type: &EventType,
+    include_dnf: bool,
+    simulation_count: u32,
+) -> Vec<SimulationResult> {
+    let num_competitors = competitors.len();
+
+    // Every shard's RNG is seeded independently off this base, keeping shards fully
+    // independent (no shared mutable RNG state) so they can run on separate rayon worker
+    // threads with no contention.
+    let base_seed = rand::rng().random::<u64>();
+
+    let shard_count = rayon::current_num_threads()
+        .max(1)
+        .min(simulation_count.max(1) as usize);
+
+    let shard_accumulators: Vec<Vec<CompetitorAccumulator>> = split_rounds(simulation_count, shard_count)
+        .into_par_iter()
+        .enumerate()
+        .map(|(i, rounds)| {
+            let shard_seed = base_seed ^ i as u64;
+            run_shard(competitors, event_type, include_dnf, rounds, shard_seed)
+        })
+        .collect();
+
+    let mut accumulators: Vec<CompetitorAccumulator> = (0..num_competitors)
+        .map(|_| CompetitorAccumulator::new(num_competitors))
+        .collect();
+
+    for shard in shard_accumulators {
+        for (idx, shard_acc) in shard.into_iter().enumerate() {
+            accumulators[idx].merge(&shard_acc);
+        }
+    }
+
     accumulators
         .into_iter()
         .map(|acc| acc.finalize(simulation_count, event_type))
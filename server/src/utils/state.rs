@@ -0,0 +1,26 @@
+use sqlx::PgPool;
+
+use crate::utils::cache::{SimulationCache, new_simulation_cache};
+use crate::utils::metrics::HttpMetricsRegistry;
+use crate::utils::types::{SimulationEndpointResults, SimulationHistoryResponse};
+
+/// Shared application state: the DB pool, the per-endpoint simulation result caches, and the
+/// HTTP-facing metrics registry.
+#[derive(Clone)]
+pub struct AppState {
+    pub pool: PgPool,
+    pub simulation_cache: SimulationCache<SimulationEndpointResults>,
+    pub history_cache: SimulationCache<SimulationHistoryResponse>,
+    pub http_metrics: HttpMetricsRegistry,
+}
+
+impl AppState {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            simulation_cache: new_simulation_cache(),
+            history_cache: new_simulation_cache(),
+            http_metrics: HttpMetricsRegistry::new(),
+        }
+    }
+}
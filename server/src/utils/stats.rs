@@ -0,0 +1,92 @@
+use super::types::ConfidenceInterval;
+
+// z-score for a 95% confidence interval.
+const CONFIDENCE_Z: f64 = 1.96;
+
+/// Binomial standard error of a proportion estimated from `n` Monte Carlo trials: `sqrt(p(1-p)/n)`.
+pub fn standard_error(p: f64, n: u32) -> f64 {
+    if n == 0 {
+        return 0.0;
+    }
+    (p * (1.0 - p) / n as f64).sqrt()
+}
+
+/// Half-width of the 95% Wilson score interval for a proportion p_hat = k/n. Unlike the naive
+/// normal approximation, this stays well-behaved at small n and near p_hat = 0 or 1.
+pub fn wilson_half_width(p_hat: f64, n: u32) -> f64 {
+    if n == 0 {
+        return 0.5;
+    }
+    let n = n as f64;
+    let z2 = CONFIDENCE_Z * CONFIDENCE_Z;
+    (CONFIDENCE_Z / (1.0 + z2 / n)) * (p_hat * (1.0 - p_hat) / n + z2 / (4.0 * n * n)).sqrt()
+}
+
+/// 95% Wilson score interval for a proportion p_hat = k/n.
+pub fn wilson_interval(p_hat: f64, n: u32) -> ConfidenceInterval {
+    if n == 0 {
+        return ConfidenceInterval {
+            lower: 0.0,
+            upper: 1.0,
+        };
+    }
+    let n_f = n as f64;
+    let z2 = CONFIDENCE_Z * CONFIDENCE_Z;
+    let center = (p_hat + z2 / (2.0 * n_f)) / (1.0 + z2 / n_f);
+    let half_width = wilson_half_width(p_hat, n);
+
+    ConfidenceInterval {
+        lower: (center - half_width).max(0.0),
+        upper: (center + half_width).min(1.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_error_is_zero_for_zero_trials() {
+        assert_eq!(standard_error(0.5, 0), 0.0);
+    }
+
+    #[test]
+    fn standard_error_matches_binomial_formula() {
+        // sqrt(0.5 * 0.5 / 100) = 0.05
+        assert!((standard_error(0.5, 100) - 0.05).abs() < 1e-12);
+    }
+
+    #[test]
+    fn wilson_half_width_is_maximally_uncertain_for_zero_trials() {
+        assert_eq!(wilson_half_width(0.5, 0), 0.5);
+    }
+
+    #[test]
+    fn wilson_half_width_shrinks_as_n_grows() {
+        let small_n = wilson_half_width(0.5, 100);
+        let large_n = wilson_half_width(0.5, 10_000);
+        assert!(large_n < small_n);
+    }
+
+    #[test]
+    fn wilson_interval_covers_the_point_estimate() {
+        let ci = wilson_interval(0.5, 1_000);
+        assert!(ci.lower < 0.5 && 0.5 < ci.upper);
+    }
+
+    #[test]
+    fn wilson_interval_is_full_range_for_zero_trials() {
+        let ci = wilson_interval(0.5, 0);
+        assert_eq!(ci.lower, 0.0);
+        assert_eq!(ci.upper, 1.0);
+    }
+
+    #[test]
+    fn wilson_interval_stays_within_unit_range_near_the_boundary() {
+        // p_hat = 0 would push a naive normal-approximation interval below 0.
+        let ci = wilson_interval(0.0, 20);
+        assert!(ci.lower >= 0.0);
+        assert!(ci.upper <= 1.0);
+        assert!(ci.lower < ci.upper);
+    }
+}
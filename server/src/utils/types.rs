@@ -11,6 +11,9 @@ pub enum EventType {
     Mo3,
     Bo3,
     Fmc,
+    /// Multi-Blind (333mbf): a single best-of-1 attempt, ranked by points (solved − missed)
+    /// descending, then time ascending.
+    MultiBlind,
 }
 
 impl EventType {
@@ -22,6 +25,7 @@ impl EventType {
             "666" | "777" => Some(Self::Mo3),
             "333fm" => Some(Self::Fmc),
             "444bf" | "555bf" => Some(Self::Bo3),
+            "333mbf" => Some(Self::MultiBlind),
             _ => None,
         }
     }
@@ -44,6 +48,18 @@ pub struct CompetitorStats {
     pub num_non_dnf_results: u32,
 }
 
+/// Multi-Blind stats: points and time are modeled as two separate weighted distributions
+/// (rather than a single skew-normal fit over times), since a result is fundamentally a
+/// (points, time) pair rather than a single magnitude.
+#[derive(Debug)]
+pub struct MultiBlindStats {
+    pub mean_points: f32,
+    pub points_stdev: f32,
+    pub mean_time: f32,
+    pub time_stdev: f32,
+    pub dnf_rate: f32,
+}
+
 // --- REQUEST/RESPONSE TYPES ---
 #[derive(Debug, Deserialize)]
 pub struct SimulationRequest {
@@ -54,6 +70,14 @@ pub struct SimulationRequest {
     pub half_life: f32,
     pub entered_times: Option<Vec<Vec<i32>>>, // Optional manual overrides
     pub include_dnf: Option<bool>,
+    /// Optional RNG seed. When set, the simulation is fully deterministic (same inputs, same
+    /// seed -> same win/pod/rank numbers), which makes the result shareable as a reproducible
+    /// "scenario" link.
+    pub seed: Option<u64>,
+    /// Optional adaptive-stopping tolerance: once the current leader's win-chance 95% Wilson
+    /// interval half-width drops to or below this, the run stops early instead of always
+    /// spending the full simulation count. `None` always runs the full count.
+    pub target_win_chance_ci_half_width: Option<f64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -64,6 +88,9 @@ pub struct SimulationHistoryRequest {
     pub end_date: NaiveDate,
     pub half_life: f32,
     pub include_dnf: Option<bool>,
+    /// Same adaptive-stopping tolerance as [`SimulationRequest::target_win_chance_ci_half_width`],
+    /// applied independently to each history point's simulation.
+    pub target_win_chance_ci_half_width: Option<f64>,
 }
 
 #[derive(Debug, FromRow)]
@@ -73,16 +100,66 @@ pub struct CompetitorRow {
     pub value: i32,
 }
 
+/// A 95% confidence interval, reported alongside a Monte Carlo point estimate so a narrow
+/// 0.51 vs 0.49 split can be told apart from sampling noise.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct ConfidenceInterval {
+    pub lower: f64,
+    pub upper: f64,
+}
+
+/// Approximate p5/median/p90/p95 markers read off a [`crate::utils::quantile::QuantileSummary`],
+/// each within that summary's error bound rather than snapped to a fixed histogram resolution.
+/// `None` when the summary has no samples (e.g. an all-DNF competitor).
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct PercentileSummary {
+    pub p5: Option<i32>,
+    pub p50: Option<i32>,
+    pub p90: Option<i32>,
+    pub p95: Option<i32>,
+}
+
+/// Mean/standard-deviation/min/max over a simulated result stream, from an online
+/// `sum`/`sum_sq`/`min`/`max` accumulator. `None` when nothing was ever recorded (e.g. an
+/// all-DNF competitor with no finite singles/averages).
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct MomentSummary {
+    pub mean: f64,
+    pub std_dev: f64,
+    pub min: i32,
+    pub max: i32,
+}
+
 #[derive(Serialize)]
 pub struct CompetitorSimulationResult {
     pub name: String,
     pub id: String,
     pub win_chance: f64,
+    /// Binomial standard error of `win_chance`, i.e. sqrt(p(1-p)/N) over the simulation count.
+    pub win_chance_se: f64,
+    /// 95% Wilson score interval for `win_chance`.
+    pub win_chance_ci: ConfidenceInterval,
     pub pod_chance: f64,
+    /// Binomial standard error of `pod_chance`.
+    pub pod_chance_se: f64,
+    /// 95% Wilson score interval for `pod_chance`.
+    pub pod_chance_ci: ConfidenceInterval,
     pub expected_rank: f64,
+    /// Standard error of `expected_rank`, from the running per-iteration rank variance.
+    pub expected_rank_se: f64,
+    /// 95% interval for `expected_rank`, from the running per-iteration rank variance.
+    pub expected_rank_ci: ConfidenceInterval,
     pub sample_size: u32,
     pub mean_no_dnf: u32,
     pub histogram: ChartData,
+    /// Percentile markers over individual solve/attempt times.
+    pub single_percentiles: PercentileSummary,
+    /// Percentile markers over the round result (average/mean/best, per event format).
+    pub average_percentiles: PercentileSummary,
+    /// Mean/std-dev/min/max over individual solve/attempt times.
+    pub single_moments: Option<MomentSummary>,
+    /// Mean/std-dev/min/max over the round result (average/mean/best, per event format).
+    pub average_moments: Option<MomentSummary>,
 }
 
 #[derive(Serialize)]
@@ -96,11 +173,18 @@ pub struct CompetitorHistoryStat {
     pub id: String,
     pub name: String,
     pub win_chance: f64,
+    pub win_chance_se: f64,
     pub pod_chance: f64,
+    pub pod_chance_se: f64,
     pub expected_rank: f64,
     pub sample_size: u32,
 }
 
+#[derive(Serialize)]
+pub struct SimulationHistoryResponse {
+    pub history: Vec<HistoryPoint>,
+}
+
 #[derive(Serialize)]
 pub struct FullHistogramChartData {
     pub single: ChartData,
@@ -113,3 +197,57 @@ pub struct SimulationEndpointResults {
     pub full_histogram: FullHistogramChartData,
     pub rank_histogram: ChartData,
 }
+
+#[derive(Debug, Deserialize)]
+pub struct CalibrateHalfLifeRequest {
+    pub competitor_ids: Vec<String>,
+    pub event_id: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+#[derive(Serialize)]
+pub struct CalibrateHalfLifeResponse {
+    /// Data-driven `half_life` (in days) that maximized held-out log-likelihood across the
+    /// requested field, as a default to offer in place of a hand-tuned constant.
+    pub half_life: f32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RatingRequest {
+    pub competitor_ids: Vec<String>,
+    pub event_id: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HeadToHeadRequest {
+    pub competitor_a: String,
+    pub competitor_b: String,
+    pub event_id: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+#[derive(Serialize)]
+pub struct CompetitorRating {
+    pub id: String,
+    pub name: String,
+    pub rating: f64,
+    pub rd: f64,
+    pub volatility: f64,
+}
+
+#[derive(Serialize)]
+pub struct RatingEndpointResults {
+    pub ratings: Vec<CompetitorRating>,
+}
+
+#[derive(Serialize)]
+pub struct HeadToHeadResult {
+    pub competitor_a: CompetitorRating,
+    pub competitor_b: CompetitorRating,
+    /// Probability that `competitor_a` beats `competitor_b`, from the Glicko-2 expected score.
+    pub win_probability_a: f64,
+}